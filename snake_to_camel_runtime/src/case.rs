@@ -0,0 +1,342 @@
+// 运行时命名转换, 与GenCamelCase派生宏生成字段名时使用的规则完全一致(派生宏直接调用这里的函数)
+
+/// 按派生宏的规则拆分单词
+///
+/// 非字母数字字符作为分隔符; 小写字母后接大写字母时断开(`userId` -> `user`, `Id`);
+/// 连续大写字母后接小写字母时在最后一个大写字母前断开(`HTTPServer` -> `HTTP`, `Server`)。
+/// 数字不改变大小写状态, 跟随前一个单词。
+/// 返回的bool表示单词前是否有分隔符。
+fn split_words(s: &str) -> Vec<(&str, bool)> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum WordMode {
+        // 当前单词中还没有出现有大小写的字符
+        Boundary,
+        // 当前单词中上一个有大小写的字符是小写
+        Lowercase,
+        // 当前单词中上一个有大小写的字符是大写
+        Uppercase,
+    }
+
+    let mut words = Vec::new();
+    for word in s.split(|c: char| !c.is_alphanumeric()) {
+        let mut char_indices = word.char_indices().peekable();
+        let mut init = 0;
+        let mut mode = WordMode::Boundary;
+        let mut separated = true;
+        while let Some((i, c)) = char_indices.next() {
+            if let Some(&(next_i, next)) = char_indices.peek() {
+                let next_mode = if c.is_lowercase() {
+                    WordMode::Lowercase
+                } else if c.is_uppercase() {
+                    WordMode::Uppercase
+                } else {
+                    mode
+                };
+                if next_mode == WordMode::Lowercase && next.is_uppercase() {
+                    // 小写字母后接大写字母, 在大写字母前断开
+                    words.push((&word[init..next_i], separated));
+                    separated = false;
+                    init = next_i;
+                    mode = WordMode::Boundary;
+                } else if mode == WordMode::Uppercase && c.is_uppercase() && next.is_lowercase() {
+                    // 连续大写字母后接小写字母, 在当前字母前断开
+                    words.push((&word[init..i], separated));
+                    separated = false;
+                    init = i;
+                    mode = WordMode::Boundary;
+                } else {
+                    mode = next_mode;
+                }
+            } else {
+                words.push((&word[init..], separated));
+                break;
+            }
+        }
+    }
+    words
+}
+
+fn push_lowercase(out: &mut String, word: &str) {
+    let mut chars = word.chars().peekable();
+    while let Some(c) = chars.next() {
+        // 词尾的Σ小写为ς
+        if c == 'Σ' && chars.peek().is_none() {
+            out.push('ς');
+        } else {
+            out.extend(c.to_lowercase());
+        }
+    }
+}
+
+fn push_uppercase(out: &mut String, word: &str) {
+    out.extend(word.chars().flat_map(char::to_uppercase));
+}
+
+fn push_capitalized(out: &mut String, word: &str) {
+    let mut char_indices = word.char_indices();
+    if let Some((_, c)) = char_indices.next() {
+        out.extend(c.to_uppercase());
+        if let Some((i, _)) = char_indices.next() {
+            push_lowercase(out, &word[i..]);
+        }
+    }
+}
+
+/// 数字的单词边界规则
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Digits {
+    /// 数字属于前一个单词, 不产生新的单词边界(`v2token` -> `v2token`, `address_line_1` -> `addressLine1`)
+    #[default]
+    Attach,
+    /// 连续的数字作为单独的单词, 与lodash的camelCase一致(`v2token` -> `v2Token`, `addressLine1` -> `address_line_1`)
+    NewWord,
+    /// 与Attach相同, 但数字两侧原有的分隔符保持原样, 分隔符后的单词按名称开头处理
+    /// (`address_line_1` -> `addressLine_1`, `v2_token` -> `v2_token`)
+    Preserve,
+}
+
+/// 命名转换选项, 对应gen_camel中的命名配置
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CaseOptions {
+    acronyms: Vec<String>,
+    digits: Digits,
+    dictionary: Vec<(String, String)>,
+}
+
+impl CaseOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 缩写词列表(不区分大小写), 例如`["ID", "URL"]`
+    ///
+    /// 缩写词在camelCase中保持全大写(`user_id` -> `userID`), 位于开头时全小写(`http_url` -> `httpURL`),
+    /// 相邻的缩写词各自保持全大写(`user_id_url` -> `userIDURL`)。
+    pub fn acronyms<I, S>(mut self, acronyms: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.acronyms
+            .extend(acronyms.into_iter().map(|s| s.as_ref().to_uppercase()));
+        self
+    }
+
+    /// 数字的单词边界规则, 默认为[`Digits::Attach`]
+    pub fn digits(mut self, digits: Digits) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// 单词替换表(不区分大小写), 例如`[("qty", "quantity"), ("addr", "address")]`
+    ///
+    /// 替换在拆分单词后、应用缩写词和数字规则前逐个单词进行, 替换后的值会再次拆分单词,
+    /// 因此可以替换为多个单词(`("tel", "phone_number")`)。
+    pub fn dictionary<I, K, V>(mut self, dictionary: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        self.dictionary.extend(
+            dictionary
+                .into_iter()
+                .map(|(word, replacement)| (word.as_ref().to_lowercase(), replacement.into())),
+        );
+        self
+    }
+
+    // 单词在替换表中对应的值
+    fn replacement(&self, word: &str) -> Option<&str> {
+        if self.dictionary.is_empty() {
+            return None;
+        }
+        let word = word.to_lowercase();
+        self.dictionary
+            .iter()
+            .find(|(from, _)| *from == word)
+            .map(|(_, to)| to.as_str())
+    }
+
+    fn replaced_words<'a>(&'a self, s: &'a str) -> Vec<(&'a str, bool)> {
+        let mut words = Vec::new();
+        for (word, separated) in split_words(s) {
+            match self.replacement(word) {
+                Some(replacement) => words.extend(
+                    split_words(replacement)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, (word, _))| (word, separated && i == 0)),
+                ),
+                None => words.push((word, separated)),
+            }
+        }
+        words
+    }
+
+    fn is_acronym(&self, word: &str) -> bool {
+        !self.acronyms.is_empty() && self.acronyms.contains(&word.to_uppercase())
+    }
+
+    // 将全大写的单词按缩写词拆分(`URLID` -> `URL`, `ID`), 优先匹配最长的缩写词, 无法完全拆分时返回None
+    fn split_acronyms<'a>(&self, word: &'a str) -> Option<Vec<&'a str>> {
+        if self.acronyms.is_empty() || word.chars().any(char::is_lowercase) {
+            return None;
+        }
+        let mut parts = Vec::new();
+        let mut rest = word;
+        while !rest.is_empty() {
+            let len = self
+                .acronyms
+                .iter()
+                .filter(|acronym| rest.starts_with(acronym.as_str()))
+                .map(String::len)
+                .max()?;
+            parts.push(&rest[..len]);
+            rest = &rest[len..];
+        }
+        Some(parts)
+    }
+
+    // 按替换表、缩写词、数字规则的顺序拆分单词
+    fn words<'a>(&'a self, s: &'a str) -> Vec<(&'a str, bool)> {
+        let mut words = Vec::new();
+        for (word, separated) in self.replaced_words(s) {
+            let parts = self
+                .split_acronyms(word)
+                .filter(|parts| parts.len() > 1)
+                .unwrap_or_else(|| vec![word]);
+            for (i, part) in parts.into_iter().enumerate() {
+                let separated = separated && i == 0;
+                if self.digits == Digits::NewWord {
+                    words.extend(
+                        split_digits(part)
+                            .into_iter()
+                            .enumerate()
+                            .map(|(j, part)| (part, separated && j == 0)),
+                    );
+                } else {
+                    words.push((part, separated));
+                }
+            }
+        }
+        words
+    }
+}
+
+// 将数字和非数字拆分为不同的单词(`sha256hex` -> `sha`, `256`, `hex`)
+fn split_digits(word: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut init = 0;
+    let mut chars = word.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        if let Some(&(next_i, next)) = chars.peek()
+            && c.is_numeric() != next.is_numeric()
+        {
+            parts.push(&word[init..next_i]);
+            init = next_i;
+        }
+    }
+    if init < word.len() {
+        parts.push(&word[init..]);
+    }
+    parts
+}
+
+fn join_words(
+    s: &str,
+    options: &CaseOptions,
+    separator: &str,
+    mut push_word: impl FnMut(&mut String, usize, &str),
+) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut prev: Option<&str> = None;
+    for (i, (word, separated)) in options.words(s).into_iter().enumerate() {
+        let mut index = i;
+        if let Some(prev) = prev {
+            // Preserve时保留数字两侧原有的分隔符, 分隔符后的单词按名称开头处理
+            if separator.is_empty()
+                && separated
+                && options.digits == Digits::Preserve
+                && (prev.ends_with(char::is_numeric) || word.starts_with(char::is_numeric))
+            {
+                out.push('_');
+                index = 0;
+            }
+            out.push_str(separator);
+        }
+        push_word(&mut out, index, word);
+        prev = Some(word);
+    }
+    out
+}
+
+/// 转换为lowerCamelCase, 与派生宏生成的字段名一致
+///
+/// ```
+/// assert_eq!(snake_to_camel_runtime::to_camel("user_name"), "userName");
+/// ```
+pub fn to_camel(s: &str) -> String {
+    to_camel_with(s, &CaseOptions::default())
+}
+
+/// 按options转换为lowerCamelCase, 与使用相同配置的派生宏生成的字段名一致
+///
+/// ```
+/// use snake_to_camel_runtime::{CaseOptions, to_camel_with};
+///
+/// let options = CaseOptions::new().acronyms(["ID", "URL"]);
+/// assert_eq!(to_camel_with("user_id", &options), "userID");
+/// assert_eq!(to_camel_with("url_path", &options), "urlPath");
+/// ```
+pub fn to_camel_with(s: &str, options: &CaseOptions) -> String {
+    join_words(s, options, "", |out, i, word| {
+        if i == 0 {
+            push_lowercase(out, word)
+        } else if options.is_acronym(word) {
+            push_uppercase(out, word)
+        } else {
+            push_capitalized(out, word)
+        }
+    })
+}
+
+/// 转换为UpperCamelCase
+pub fn to_pascal(s: &str) -> String {
+    to_pascal_with(s, &CaseOptions::default())
+}
+
+/// 按options转换为UpperCamelCase, 缩写词保持全大写
+pub fn to_pascal_with(s: &str, options: &CaseOptions) -> String {
+    join_words(s, options, "", |out, _, word| {
+        if options.is_acronym(word) {
+            push_uppercase(out, word)
+        } else {
+            push_capitalized(out, word)
+        }
+    })
+}
+
+/// 转换为snake_case, 用于将camelCase的键还原为原始字段名
+pub fn to_snake(s: &str) -> String {
+    to_snake_with(s, &CaseOptions::default())
+}
+
+/// 按options转换为snake_case, 相邻的缩写词会被拆开(`userIDURL` -> `user_id_url`)
+pub fn to_snake_with(s: &str, options: &CaseOptions) -> String {
+    join_words(s, options, "_", |out, _, word| push_lowercase(out, word))
+}
+
+/// 转换为kebab-case
+pub fn to_kebab(s: &str) -> String {
+    join_words(s, &CaseOptions::default(), "-", |out, _, word| {
+        push_lowercase(out, word)
+    })
+}
+
+/// 转换为SHOUTY_SNAKE_CASE
+pub fn to_shouty_snake(s: &str) -> String {
+    join_words(s, &CaseOptions::default(), "_", |out, _, word| {
+        push_uppercase(out, word)
+    })
+}
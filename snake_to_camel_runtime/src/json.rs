@@ -0,0 +1,131 @@
+// serde_json::Value的键名递归转换
+use crate::{CaseOptions, to_camel_with, to_snake_with};
+use serde_json::{Map, Value};
+
+/// [`camelize_value_with`]和[`snakify_value_with`]的转换选项
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KeyOptions {
+    max_depth: Option<usize>,
+    exclude_keys: Vec<String>,
+    preserve_converted: bool,
+    case_options: CaseOptions,
+}
+
+impl KeyOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 最多转换几层对象的键, 顶层对象为第1层, 默认不限制
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// 不转换的键, 按原始键名匹配, 其值也保持原样不再递归
+    pub fn exclude_keys<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.exclude_keys.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// 已经是目标格式的键保持原样, 例如camelize时保留`userID`而不是转换为`userId`
+    pub fn preserve_converted(mut self, preserve: bool) -> Self {
+        self.preserve_converted = preserve;
+        self
+    }
+
+    /// 键名转换使用的命名选项, 与派生宏的gen_camel命名配置保持一致
+    pub fn case_options(mut self, case_options: CaseOptions) -> Self {
+        self.case_options = case_options;
+        self
+    }
+}
+
+/// 递归地将对象的键转换为camelCase
+///
+/// ```
+/// let mut value = serde_json::json!({ "user_name": "alice", "tags": [{ "tag_id": 1 }] });
+/// snake_to_camel_runtime::camelize_value(&mut value);
+/// assert_eq!(value, serde_json::json!({ "userName": "alice", "tags": [{ "tagId": 1 }] }));
+/// ```
+pub fn camelize_value(value: &mut Value) {
+    camelize_value_with(value, &KeyOptions::default());
+}
+
+/// 按options递归地将对象的键转换为camelCase
+///
+/// 数组中的对象与数组本身处于同一层; 多个键转换后重名时保留后处理的键。
+pub fn camelize_value_with(value: &mut Value, options: &KeyOptions) {
+    convert_value(value, options, 1, &|key| {
+        if options.preserve_converted && is_camel_case(key) {
+            None
+        } else {
+            Some(to_camel_with(key, &options.case_options))
+        }
+    });
+}
+
+/// 递归地将对象的键转换为snake_case
+pub fn snakify_value(value: &mut Value) {
+    snakify_value_with(value, &KeyOptions::default());
+}
+
+/// 按options递归地将对象的键转换为snake_case
+pub fn snakify_value_with(value: &mut Value, options: &KeyOptions) {
+    convert_value(value, options, 1, &|key| {
+        if options.preserve_converted && is_snake_case(key) {
+            None
+        } else {
+            Some(to_snake_with(key, &options.case_options))
+        }
+    });
+}
+
+// 没有分隔符且不以大写字母开头
+fn is_camel_case(key: &str) -> bool {
+    key.chars().all(char::is_alphanumeric) && !key.starts_with(char::is_uppercase)
+}
+
+// 没有大写字母, 只用下划线分隔
+fn is_snake_case(key: &str) -> bool {
+    key.chars()
+        .all(|c| c == '_' || (c.is_alphanumeric() && !c.is_uppercase()))
+}
+
+fn convert_value(
+    value: &mut Value,
+    options: &KeyOptions,
+    depth: usize,
+    convert_key: &dyn Fn(&str) -> Option<String>,
+) {
+    if options.max_depth.is_some_and(|max_depth| depth > max_depth) {
+        return;
+    }
+    match value {
+        Value::Object(map) => {
+            let old = std::mem::take(map);
+            let mut new = Map::with_capacity(old.len());
+            for (key, mut value) in old {
+                if options.exclude_keys.contains(&key) {
+                    new.insert(key, value);
+                    continue;
+                }
+                convert_value(&mut value, options, depth + 1, convert_key);
+                let key = convert_key(&key).unwrap_or(key);
+                new.insert(key, value);
+            }
+            *map = new;
+        }
+        // 数组不增加层数
+        Value::Array(values) => {
+            for value in values {
+                convert_value(value, options, depth, convert_key);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,61 @@
+//! snake_to_camel的运行时配套库
+//!
+//! `snake_to_camel`是proc-macro crate, 只能导出宏, 这里提供派生宏生成代码所依赖的trait和函数,
+//! 便于编写针对"原始结构体与其camelCase镜像"的泛型代码, 以及与派生宏命名规则一致的运行时命名转换。
+
+mod case;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "serde")]
+mod serde_adapter;
+
+pub use case::{
+    CaseOptions, Digits, to_camel, to_camel_with, to_kebab, to_pascal, to_pascal_with,
+    to_shouty_snake, to_snake, to_snake_with,
+};
+#[cfg(feature = "json")]
+pub use json::{
+    KeyOptions, camelize_value, camelize_value_with, snakify_value, snakify_value_with,
+};
+#[cfg(feature = "serde")]
+pub use serde_adapter::{Camel, CamelCompound, CamelDeserializer, CamelSerializer};
+
+/// 原始结构体与其camelCase镜像结构体之间的双向转换
+///
+/// `#[derive(GenCamelCase)]`会为每个配置了`gen_camel(mirror_trait)`的id
+/// 在原始结构体上实现此trait, `ID`为[`mirror_id`]计算的id哈希,
+/// 默认id(`""`)对应`0`, 因此可以直接写`T: CamelMirror`。
+///
+/// ```ignore
+/// fn respond<T: CamelMirror>(t: T) -> T::Mirror {
+///     t.to_mirror()
+/// }
+/// ```
+pub trait CamelMirror<const ID: u64 = 0>: Sized {
+    /// 生成的camelCase镜像结构体
+    type Mirror;
+
+    /// 转换为镜像结构体
+    fn to_mirror(self) -> Self::Mirror;
+
+    /// 从镜像结构体转换回原始结构体
+    fn from_mirror(mirror: Self::Mirror) -> Self;
+}
+
+/// 计算gen_camel id对应的[`CamelMirror`]常量参数
+///
+/// 使用64位FNV-1a哈希, 空字符串(默认id)固定为`0`。
+pub const fn mirror_id(id: &str) -> u64 {
+    let bytes = id.as_bytes();
+    if bytes.is_empty() {
+        return 0;
+    }
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+    hash
+}
@@ -0,0 +1,754 @@
+// 序列化/反序列化时实时重命名结构体字段的serde适配器
+use crate::{CaseOptions, to_camel_with};
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, Serialize, Serializer};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+/// 结构体字段名和对应的camelCase字段名
+type Keys = (&'static [&'static str], &'static [&'static str]);
+
+/// 一组命名选项下字段名的转换缓存
+///
+/// serde要求结构体字段名为`&'static str`, 转换结果泄漏后缓存, 字段名来自类型定义, 数量有限。
+/// 只有第一次遇到的字段名需要写锁, 之后都是读取。
+struct KeyCache {
+    options: CaseOptions,
+    // 字段名 -> camelCase字段名
+    keys: RwLock<HashMap<&'static str, &'static str>>,
+    // 结构体的字段列表(按地址和长度区分) -> camelCase字段名列表
+    fields: RwLock<HashMap<(usize, usize), &'static [&'static str]>>,
+}
+
+impl KeyCache {
+    fn new(options: CaseOptions) -> Self {
+        Self {
+            options,
+            keys: Default::default(),
+            fields: Default::default(),
+        }
+    }
+
+    fn camel_key(&self, key: &'static str) -> &'static str {
+        if let Some(camel) = self
+            .keys
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+        {
+            return camel;
+        }
+        self.keys
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(key)
+            .or_insert_with(|| {
+                // 含大写字母的键已经由#[serde(rename)]等指定, 与派生宏透传的serde属性一样保持不变
+                if key.chars().any(char::is_uppercase) {
+                    key
+                } else {
+                    Box::leak(to_camel_with(key, &self.options).into_boxed_str())
+                }
+            })
+    }
+
+    // 每个结构体的字段列表只转换一次, 反序列化时逐个比较键不再访问缓存
+    fn camel_fields(&self, fields: &'static [&'static str]) -> Keys {
+        let id = (fields.as_ptr() as usize, fields.len());
+        if let Some(camel) = self
+            .fields
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&id)
+        {
+            return (fields, camel);
+        }
+        let camel = fields
+            .iter()
+            .map(|field| self.camel_key(field))
+            .collect::<Vec<_>>();
+        let camel = *self
+            .fields
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(id)
+            .or_insert_with(|| Box::leak(camel.into_boxed_slice()));
+        (fields, camel)
+    }
+}
+
+fn default_cache() -> &'static KeyCache {
+    static DEFAULT: OnceLock<KeyCache> = OnceLock::new();
+    DEFAULT.get_or_init(|| KeyCache::new(CaseOptions::default()))
+}
+
+// 每组命名选项共用一个缓存, 只在创建适配器时查找, 嵌套的值沿用同一个缓存
+fn options_cache(options: CaseOptions) -> &'static KeyCache {
+    static CACHES: RwLock<Vec<&'static KeyCache>> = RwLock::new(Vec::new());
+    if options == CaseOptions::default() {
+        return default_cache();
+    }
+    let find = |caches: &[&'static KeyCache]| {
+        caches
+            .iter()
+            .copied()
+            .find(|cache| cache.options == options)
+    };
+    if let Some(cache) = find(&CACHES.read().unwrap_or_else(PoisonError::into_inner)) {
+        return cache;
+    }
+    let mut caches = CACHES.write().unwrap_or_else(PoisonError::into_inner);
+    if let Some(cache) = find(&caches) {
+        return cache;
+    }
+    let cache: &'static KeyCache = Box::leak(Box::new(KeyCache::new(options)));
+    caches.push(cache);
+    cache
+}
+
+/// 序列化/反序列化时使用camelCase字段名的包装
+///
+/// ```ignore
+/// let json = serde_json::to_string(&Camel(&order))?;
+/// let Camel(order): Camel<Order> = serde_json::from_str(&json)?;
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Camel<T>(pub T);
+
+impl<T: Serialize> Serialize for Camel<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(CamelSerializer::new(serializer))
+    }
+}
+
+impl<'de, T: de::Deserialize<'de>> de::Deserialize<'de> for Camel<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(CamelDeserializer::new(deserializer)).map(Camel)
+    }
+}
+
+/// 将结构体字段名转换为camelCase后交给内部Serializer, 不需要先生成镜像结构体
+///
+/// 只重命名结构体的字段, map的键保持原样。与派生宏一致, 枚举(包括结构体变体的字段和变体中的值)
+/// 按原样序列化, 因为派生宏不会为枚举生成镜像类型。
+///
+/// 含大写字母的字段名(例如`#[serde(rename = "ID")]`)保持不变, 但重命名为snake_case的字段名仍会被转换。
+/// 使用`#[serde(flatten)]`的结构体由serde按map序列化, 其中的字段名(包括展开的字段)保持原样,
+/// 反序列化时同样需要snake_case的键。
+pub struct CamelSerializer<S> {
+    inner: S,
+    cache: &'static KeyCache,
+}
+
+impl<S> CamelSerializer<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            cache: default_cache(),
+        }
+    }
+
+    /// 使用与gen_camel的acronyms, digits和dictionary对应的命名选项
+    ///
+    /// ```ignore
+    /// let options = CaseOptions::new().acronyms(["ID"]);
+    /// let value = user.serialize(CamelSerializer::with_options(serde_json::value::Serializer, options))?;
+    /// ```
+    pub fn with_options(inner: S, options: CaseOptions) -> Self {
+        Self {
+            inner,
+            cache: options_cache(options),
+        }
+    }
+}
+
+/// [`CamelSerializer`]的复合类型序列化器
+pub struct CamelCompound<C> {
+    inner: C,
+    // 为None时(枚举变体)字段名和值都保持原样
+    cache: Option<&'static KeyCache>,
+}
+
+impl<C> CamelCompound<C> {
+    fn nested<'a, T: ?Sized>(&self, value: &'a T) -> Nested<'a, T> {
+        Nested {
+            value,
+            cache: self.cache,
+        }
+    }
+
+    fn key(&self, key: &'static str) -> &'static str {
+        match self.cache {
+            Some(cache) => cache.camel_key(key),
+            None => key,
+        }
+    }
+}
+
+// 嵌套的值使用同一组命名选项序列化, cache为None时按原样序列化
+struct Nested<'a, T: ?Sized> {
+    value: &'a T,
+    cache: Option<&'static KeyCache>,
+}
+
+impl<T: ?Sized + Serialize> Serialize for Nested<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.cache {
+            Some(cache) => self.value.serialize(CamelSerializer {
+                inner: serializer,
+                cache,
+            }),
+            None => self.value.serialize(serializer),
+        }
+    }
+}
+
+impl<S> CamelSerializer<S> {
+    fn nested<'a, T: ?Sized>(&self, value: &'a T) -> Nested<'a, T> {
+        Nested {
+            value,
+            cache: Some(self.cache),
+        }
+    }
+}
+
+macro_rules! forward_serialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method(self, $($arg: $ty),*) -> Result<Self::Ok, Self::Error> {
+                self.inner.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for CamelSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = CamelCompound<S::SerializeSeq>;
+    type SerializeTuple = CamelCompound<S::SerializeTuple>;
+    type SerializeTupleStruct = CamelCompound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = CamelCompound<S::SerializeTupleVariant>;
+    type SerializeMap = CamelCompound<S::SerializeMap>;
+    type SerializeStruct = CamelCompound<S::SerializeStruct>;
+    type SerializeStructVariant = CamelCompound<S::SerializeStructVariant>;
+
+    forward_serialize! {
+        serialize_bool(v: bool);
+        serialize_i8(v: i8);
+        serialize_i16(v: i16);
+        serialize_i32(v: i32);
+        serialize_i64(v: i64);
+        serialize_i128(v: i128);
+        serialize_u8(v: u8);
+        serialize_u16(v: u16);
+        serialize_u32(v: u32);
+        serialize_u64(v: u64);
+        serialize_u128(v: u128);
+        serialize_f32(v: f32);
+        serialize_f64(v: f64);
+        serialize_char(v: char);
+        serialize_str(v: &str);
+        serialize_bytes(v: &[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(name: &'static str);
+        serialize_unit_variant(name: &'static str, variant_index: u32, variant: &'static str);
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        let value = self.nested(value);
+        self.inner.serialize_some(&value)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let value = self.nested(value);
+        self.inner.serialize_newtype_struct(name, &value)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner
+            .serialize_newtype_variant(name, variant_index, variant, value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        self.inner.serialize_seq(len).map(|inner| CamelCompound {
+            inner,
+            cache: Some(self.cache),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.inner.serialize_tuple(len).map(|inner| CamelCompound {
+            inner,
+            cache: Some(self.cache),
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.inner
+            .serialize_tuple_struct(name, len)
+            .map(|inner| CamelCompound {
+                inner,
+                cache: Some(self.cache),
+            })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        self.inner
+            .serialize_tuple_variant(name, variant_index, variant, len)
+            .map(|inner| CamelCompound { inner, cache: None })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        self.inner.serialize_map(len).map(|inner| CamelCompound {
+            inner,
+            cache: Some(self.cache),
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.inner
+            .serialize_struct(name, len)
+            .map(|inner| CamelCompound {
+                inner,
+                cache: Some(self.cache),
+            })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.inner
+            .serialize_struct_variant(name, variant_index, variant, len)
+            .map(|inner| CamelCompound { inner, cache: None })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! impl_compound {
+    ($($trait:ident::$method:ident;)*) => {
+        $(
+            impl<C: ser::$trait> ser::$trait for CamelCompound<C> {
+                type Ok = C::Ok;
+                type Error = C::Error;
+
+                fn $method<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+                    let value = self.nested(value);
+                    self.inner.$method(&value)
+                }
+
+                fn end(self) -> Result<Self::Ok, Self::Error> {
+                    self.inner.end()
+                }
+            }
+        )*
+    };
+}
+
+impl_compound! {
+    SerializeSeq::serialize_element;
+    SerializeTuple::serialize_element;
+    SerializeTupleStruct::serialize_field;
+    SerializeTupleVariant::serialize_field;
+}
+
+impl<C: ser::SerializeMap> ser::SerializeMap for CamelCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        let key = self.nested(key);
+        self.inner.serialize_key(&key)
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let value = self.nested(value);
+        self.inner.serialize_value(&value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+macro_rules! impl_struct_compound {
+    ($($trait:ident;)*) => {
+        $(
+            impl<C: ser::$trait> ser::$trait for CamelCompound<C> {
+                type Ok = C::Ok;
+                type Error = C::Error;
+
+                fn serialize_field<T: ?Sized + Serialize>(
+                    &mut self,
+                    key: &'static str,
+                    value: &T,
+                ) -> Result<(), Self::Error> {
+                    let value = self.nested(value);
+                    self.inner.serialize_field(self.key(key), &value)
+                }
+
+                fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+                    self.inner.skip_field(self.key(key))
+                }
+
+                fn end(self) -> Result<Self::Ok, Self::Error> {
+                    self.inner.end()
+                }
+            }
+        )*
+    };
+}
+
+impl_struct_compound! {
+    SerializeStruct;
+    SerializeStructVariant;
+}
+
+/// 将输入中的camelCase字段名还原为结构体字段名后交给目标类型, 与[`CamelSerializer`]对应
+///
+/// 只还原结构体字段名, 匹配不到字段的键保持原样, 枚举按原样反序列化。
+pub struct CamelDeserializer<D> {
+    inner: D,
+    cache: &'static KeyCache,
+    // 正在反序列化结构体的键时, 用于将camelCase键还原为字段名
+    keys: Option<Keys>,
+}
+
+impl<D> CamelDeserializer<D> {
+    pub fn new(inner: D) -> Self {
+        Self {
+            inner,
+            cache: default_cache(),
+            keys: None,
+        }
+    }
+
+    /// 使用与gen_camel的acronyms, digits和dictionary对应的命名选项, 与[`CamelSerializer::with_options`]对应
+    pub fn with_options(inner: D, options: CaseOptions) -> Self {
+        Self {
+            inner,
+            cache: options_cache(options),
+            keys: None,
+        }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error> {
+                self.inner.$method($($arg,)* CamelVisitor::new(visitor, self.cache, None, self.keys))
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for CamelDeserializer<D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any();
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_i128();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_u128();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_option();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_identifier();
+        deserialize_ignored_any();
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let keys = self.cache.camel_fields(fields);
+        self.inner.deserialize_struct(
+            name,
+            fields,
+            CamelVisitor::new(visitor, self.cache, Some(keys), None),
+        )
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        // 与派生宏一致, 枚举的变体和其中的值都不转换
+        self.inner.deserialize_enum(name, variants, visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+// 包装Visitor, 使嵌套的值继续通过CamelDeserializer反序列化
+struct CamelVisitor<V> {
+    inner: V,
+    cache: &'static KeyCache,
+    // 访问的是结构体时的字段名
+    fields: Option<Keys>,
+    // 访问的是结构体的键时的字段名
+    keys: Option<Keys>,
+}
+
+impl<V> CamelVisitor<V> {
+    fn new(inner: V, cache: &'static KeyCache, fields: Option<Keys>, keys: Option<Keys>) -> Self {
+        Self {
+            inner,
+            cache,
+            fields,
+            keys,
+        }
+    }
+
+    // camelCase键对应的字段名
+    fn field(&self, key: &str) -> Option<&'static str> {
+        let (fields, camel_fields) = self.keys?;
+        camel_fields
+            .iter()
+            .position(|camel| *camel == key)
+            .map(|i| fields[i])
+    }
+
+    fn access<A>(&self, inner: A, fields: Option<Keys>) -> CamelAccess<A> {
+        CamelAccess {
+            inner,
+            cache: self.cache,
+            fields,
+        }
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for CamelVisitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i64(i64);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u64(u64);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+        visit_bytes(&[u8]);
+        visit_borrowed_bytes(&'de [u8]);
+        visit_byte_buf(Vec<u8>);
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        match self.field(v) {
+            Some(field) => self.inner.visit_borrowed_str(field),
+            None => self.inner.visit_str(v),
+        }
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        match self.field(v) {
+            Some(field) => self.inner.visit_borrowed_str(field),
+            None => self.inner.visit_borrowed_str(v),
+        }
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        match self.field(&v) {
+            Some(field) => self.inner.visit_borrowed_str(field),
+            None => self.inner.visit_string(v),
+        }
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner.visit_some(CamelDeserializer {
+            inner: deserializer,
+            cache: self.cache,
+            keys: None,
+        })
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.inner.visit_newtype_struct(CamelDeserializer {
+            inner: deserializer,
+            cache: self.cache,
+            keys: None,
+        })
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let seq = self.access(seq, None);
+        self.inner.visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let map = self.access(map, self.fields);
+        self.inner.visit_map(map)
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        // 与派生宏一致, 枚举的变体和其中的值都不转换
+        self.inner.visit_enum(data)
+    }
+}
+
+// 包装SeqAccess/MapAccess
+struct CamelAccess<A> {
+    inner: A,
+    cache: &'static KeyCache,
+    // map对应结构体时的字段名
+    fields: Option<Keys>,
+}
+
+impl<A> CamelAccess<A> {
+    fn seed<S>(&self, inner: S, keys: Option<Keys>) -> CamelSeed<S> {
+        CamelSeed {
+            inner,
+            cache: self.cache,
+            keys,
+        }
+    }
+}
+
+// 包装DeserializeSeed, keys不为None时反序列化的是结构体的键
+struct CamelSeed<S> {
+    inner: S,
+    cache: &'static KeyCache,
+    keys: Option<Keys>,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for CamelSeed<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner.deserialize(CamelDeserializer {
+            inner: deserializer,
+            cache: self.cache,
+            keys: self.keys,
+        })
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for CamelAccess<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, A::Error> {
+        let seed = self.seed(seed, None);
+        self.inner.next_element_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for CamelAccess<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        let seed = self.seed(seed, self.fields);
+        self.inner.next_key_seed(seed)
+    }
+
+    fn next_value_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<T::Value, A::Error> {
+        let seed = self.seed(seed, None);
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
@@ -0,0 +1,99 @@
+mod fixtures;
+
+use snake_to_camel_runtime::{
+    CaseOptions, Digits, to_camel, to_camel_with, to_kebab, to_pascal, to_pascal_with,
+    to_shouty_snake, to_snake, to_snake_with,
+};
+
+#[test]
+fn test_case_fixtures() {
+    for &(input, camel, pascal, snake) in fixtures::CASES {
+        assert_eq!(to_camel(input), camel, "to_camel({input:?})");
+        assert_eq!(to_pascal(input), pascal, "to_pascal({input:?})");
+        assert_eq!(to_snake(input), snake, "to_snake({input:?})");
+        assert_eq!(
+            to_kebab(input),
+            snake.replace('_', "-"),
+            "to_kebab({input:?})"
+        );
+        assert_eq!(
+            to_shouty_snake(input),
+            snake.to_uppercase(),
+            "to_shouty_snake({input:?})"
+        );
+    }
+}
+
+#[test]
+fn test_acronym_fixtures() {
+    let options = CaseOptions::new().acronyms(fixtures::ACRONYMS);
+    for &(input, camel, pascal, snake) in fixtures::ACRONYM_CASES {
+        assert_eq!(
+            to_camel_with(input, &options),
+            camel,
+            "to_camel_with({input:?})"
+        );
+        assert_eq!(
+            to_pascal_with(input, &options),
+            pascal,
+            "to_pascal_with({input:?})"
+        );
+        assert_eq!(
+            to_snake_with(input, &options),
+            snake,
+            "to_snake_with({input:?})"
+        );
+    }
+    // 未配置缩写词时与默认规则一致
+    for &(input, camel, _, _) in fixtures::CASES {
+        assert_eq!(to_camel_with(input, &CaseOptions::new()), camel);
+    }
+}
+
+#[test]
+fn test_digit_fixtures() {
+    let attach = CaseOptions::new().digits(Digits::Attach);
+    let new_word = CaseOptions::new().digits(Digits::NewWord);
+    let preserve = CaseOptions::new().digits(Digits::Preserve);
+    for &(input, attach_camel, new_word_camel, preserve_camel) in fixtures::DIGIT_CASES {
+        assert_eq!(to_camel(input), attach_camel, "to_camel({input:?})");
+        assert_eq!(to_camel_with(input, &attach), attach_camel);
+        assert_eq!(
+            to_camel_with(input, &new_word),
+            new_word_camel,
+            "new_word({input:?})"
+        );
+        assert_eq!(
+            to_camel_with(input, &preserve),
+            preserve_camel,
+            "preserve({input:?})"
+        );
+    }
+    // new_word与lodash一致, camelCase转换回snake_case时数字单独成词
+    assert_eq!(to_snake_with("addressLine1", &new_word), "address_line_1");
+    assert_eq!(to_snake_with("addressLine1", &attach), "address_line1");
+    assert_eq!(to_snake_with("v2Token", &new_word), "v_2_token");
+    assert_eq!(to_pascal_with("sha256hex", &new_word), "Sha256Hex");
+    // preserve保留的分隔符可以原样转换回去
+    assert_eq!(to_snake_with("addressLine_1", &preserve), "address_line_1");
+}
+
+#[test]
+fn test_dictionary_fixtures() {
+    let options = CaseOptions::new().dictionary(fixtures::DICTIONARY.iter().copied());
+    for &(input, camel, snake) in fixtures::DICTIONARY_CASES {
+        assert_eq!(
+            to_camel_with(input, &options),
+            camel,
+            "to_camel_with({input:?})"
+        );
+        assert_eq!(
+            to_snake_with(input, &options),
+            snake,
+            "to_snake_with({input:?})"
+        );
+    }
+    // 替换后再应用缩写词规则
+    let options = options.acronyms(["ID"]).dictionary([("uid", "user_id")]);
+    assert_eq!(to_camel_with("order_uid", &options), "orderUserID");
+}
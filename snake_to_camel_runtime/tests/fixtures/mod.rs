@@ -0,0 +1,106 @@
+// 命名转换的共享用例表, 运行时函数和派生宏生成的字段名都以此为准
+// (输入, to_camel, to_pascal, to_snake)
+pub const CASES: &[(&str, &str, &str, &str)] = &[
+    ("user_name", "userName", "UserName", "user_name"),
+    ("user_id", "userId", "UserId", "user_id"),
+    ("http_url", "httpUrl", "HttpUrl", "http_url"),
+    ("is_active", "isActive", "IsActive", "is_active"),
+    (
+        "address_line_1",
+        "addressLine1",
+        "AddressLine1",
+        "address_line_1",
+    ),
+    ("v2_token", "v2Token", "V2Token", "v2_token"),
+    ("sha256_hex", "sha256Hex", "Sha256Hex", "sha256_hex"),
+    (
+        "_private_flag",
+        "privateFlag",
+        "PrivateFlag",
+        "private_flag",
+    ),
+    (
+        "double__underscore",
+        "doubleUnderscore",
+        "DoubleUnderscore",
+        "double_underscore",
+    ),
+    ("trailing_", "trailing", "Trailing", "trailing"),
+    ("single", "single", "Single", "single"),
+    ("userName", "userName", "UserName", "user_name"),
+    ("HTTPServer", "httpServer", "HttpServer", "http_server"),
+    (
+        "XMLHttpRequest",
+        "xmlHttpRequest",
+        "XmlHttpRequest",
+        "xml_http_request",
+    ),
+    (
+        "kebab-case-key",
+        "kebabCaseKey",
+        "KebabCaseKey",
+        "kebab_case_key",
+    ),
+    (
+        "ALREADY_SHOUTY",
+        "alreadyShouty",
+        "AlreadyShouty",
+        "already_shouty",
+    ),
+    ("", "", "", ""),
+];
+
+// 配置acronyms = "ID, URL, HTTP"时的用例
+pub const ACRONYMS: &[&str] = &["ID", "URL", "HTTP"];
+pub const ACRONYM_CASES: &[(&str, &str, &str, &str)] = &[
+    ("user_id", "userID", "UserID", "user_id"),
+    ("http_url", "httpURL", "HTTPURL", "http_url"),
+    ("url", "url", "URL", "url"),
+    ("user_id_url", "userIDURL", "UserIDURL", "user_id_url"),
+    (
+        "http_server_id",
+        "httpServerID",
+        "HTTPServerID",
+        "http_server_id",
+    ),
+    ("identity", "identity", "Identity", "identity"),
+    ("userID", "userID", "UserID", "user_id"),
+    ("httpURLID", "httpURLID", "HTTPURLID", "http_url_id"),
+];
+
+// digits规则用例: (输入, attach, new_word, preserve)
+pub const DIGIT_CASES: &[(&str, &str, &str, &str)] = &[
+    (
+        "address_line_1",
+        "addressLine1",
+        "addressLine1",
+        "addressLine_1",
+    ),
+    ("v2_token", "v2Token", "v2Token", "v2_token"),
+    ("sha256_hex", "sha256Hex", "sha256Hex", "sha256_hex"),
+    ("v2token", "v2token", "v2Token", "v2token"),
+    ("sha256hex", "sha256hex", "sha256Hex", "sha256hex"),
+    (
+        "oauth2_client_id",
+        "oauth2ClientId",
+        "oauth2ClientId",
+        "oauth2_clientId",
+    ),
+    ("line_10_20", "line1020", "line1020", "line_10_20"),
+];
+
+// 单词替换表, 与tests/names.toml一致
+pub const DICTIONARY: &[(&str, &str)] = &[
+    ("qty", "quantity"),
+    ("addr", "address"),
+    ("tel", "phone_number"),
+];
+// (输入, to_camel_with, to_snake_with)
+pub const DICTIONARY_CASES: &[(&str, &str, &str)] = &[
+    ("order_qty", "orderQuantity", "order_quantity"),
+    ("ship_addr_line_1", "shipAddressLine1", "ship_address_line_1"),
+    ("tel", "phoneNumber", "phone_number"),
+    ("home_tel", "homePhoneNumber", "home_phone_number"),
+    ("qtyx", "qtyx", "qtyx"),
+    ("orderQty", "orderQuantity", "order_quantity"),
+];
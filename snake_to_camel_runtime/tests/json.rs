@@ -0,0 +1,72 @@
+#![cfg(feature = "json")]
+
+use serde_json::json;
+use snake_to_camel_runtime::{
+    CaseOptions, KeyOptions, camelize_value, camelize_value_with, snakify_value, snakify_value_with,
+};
+
+#[test]
+fn test_camelize_value() {
+    let mut value = json!({
+        "user_name": "alice",
+        "home_address": { "street_name": "Main St" },
+        "order_items": [{ "item_id": 1 }, { "item_id": 2 }],
+        "tags": ["snake_case_value"],
+    });
+    camelize_value(&mut value);
+    assert_eq!(
+        value,
+        json!({
+            "userName": "alice",
+            "homeAddress": { "streetName": "Main St" },
+            "orderItems": [{ "itemId": 1 }, { "itemId": 2 }],
+            "tags": ["snake_case_value"],
+        })
+    );
+
+    snakify_value(&mut value);
+    assert_eq!(value["home_address"]["street_name"], "Main St");
+    assert_eq!(value["order_items"][1]["item_id"], 2);
+}
+
+#[test]
+fn test_key_options() {
+    let value = json!({
+        "user_id": 1,
+        "userID": 2,
+        "extra_data": { "raw_key": true },
+        "nested_obj": { "inner_obj": { "deep_key": 0 } },
+    });
+
+    // 只转换前两层
+    let mut depth = value.clone();
+    camelize_value_with(&mut depth, &KeyOptions::new().max_depth(2));
+    assert_eq!(depth["nestedObj"]["innerObj"], json!({ "deep_key": 0 }));
+
+    // 排除的键及其值保持原样
+    let mut excluded = value.clone();
+    camelize_value_with(
+        &mut excluded,
+        &KeyOptions::new().exclude_keys(["extra_data"]),
+    );
+    assert_eq!(excluded["extra_data"], json!({ "raw_key": true }));
+    assert_eq!(excluded["nestedObj"]["innerObj"]["deepKey"], 0);
+
+    // 已经是camelCase的键不再转换
+    let mut preserved = value.clone();
+    camelize_value_with(&mut preserved, &KeyOptions::new().preserve_converted(true));
+    assert_eq!(preserved["userID"], 2);
+    assert_eq!(preserved["userId"], 1);
+
+    // 使用与派生宏相同的缩写词配置
+    let mut acronyms = json!({ "user_id": 1, "home_url": "/" });
+    camelize_value_with(
+        &mut acronyms,
+        &KeyOptions::new().case_options(CaseOptions::new().acronyms(["ID"])),
+    );
+    assert_eq!(acronyms, json!({ "userID": 1, "homeUrl": "/" }));
+
+    let mut snake = json!({ "user_id": 1, "HTTPServer": "x" });
+    snakify_value_with(&mut snake, &KeyOptions::new().preserve_converted(true));
+    assert_eq!(snake, json!({ "user_id": 1, "http_server": "x" }));
+}
@@ -0,0 +1,145 @@
+// 每个id分别配置mirror_trait, 同名的开关会被clippy视为重复的属性
+#![allow(clippy::duplicated_attributes)]
+use snake_to_camel::GenCamelCase;
+use snake_to_camel_runtime::{CamelMirror, mirror_id};
+
+// 1. 配置了mirror_trait的默认id和命名id都实现CamelMirror
+#[derive(GenCamelCase, Debug, Clone, PartialEq)]
+#[gen_camel(mirror_trait, derive = "Debug, Clone, PartialEq")]
+#[gen_camel(id = "dto", suffix = "Dto", mirror_trait, derive = "Debug")]
+pub struct UserInfo {
+    pub user_id: u64,
+    pub user_name: String,
+}
+
+// 生成的结构体
+// impl CamelMirror for UserInfo { type Mirror = UserInfoVo; ... }
+// impl CamelMirror<{ mirror_id("dto") }> for UserInfo { type Mirror = UserInfoDto; ... }
+
+// 2. 泛型结构体, 未配置mirror_trait的id不实现CamelMirror
+#[derive(GenCamelCase, Debug, PartialEq)]
+#[gen_camel(mirror_trait, derive = "Debug, PartialEq")]
+#[gen_camel(id = "out", suffix = "Out", direction = "to")]
+pub struct Page<T> {
+    pub page_items: Vec<T>,
+    pub total_count: usize,
+}
+
+// 3. nested = "trait", 嵌套类型名取自子结构体同一id的CamelMirror实现, 不再按后缀推导
+#[derive(GenCamelCase, Debug, Clone, PartialEq)]
+#[gen_camel(name = "OrderItemDto", derive = "Debug, Clone, PartialEq")]
+#[gen_camel(id = "dto", name = "LineDto", mirror_trait, derive = "Debug")]
+pub struct OrderItem {
+    pub product_id: u64,
+}
+
+#[derive(GenCamelCase, Debug, Clone, PartialEq)]
+#[gen_camel(
+    name = "OrderDto",
+    type_suffix = "Dto",
+    mirror_trait,
+    derive = "Debug, Clone, PartialEq"
+)]
+#[gen_camel(
+    id = "dto",
+    name = "OrderOut",
+    nested = "trait",
+    mirror_trait,
+    derive = "Debug"
+)]
+pub struct Order {
+    pub order_items: Vec<OrderItem>,
+    pub first_item: OrderItem,
+}
+
+// 生成的结构体
+// struct OrderDto {
+//     orderItems: Vec<OrderItemDto>,
+//     firstItem: OrderItemDto,
+// }
+// struct OrderOut {
+//     orderItems: Vec<<OrderItem as CamelMirror<{ mirror_id("dto") }>>::Mirror>,  // Vec<LineDto>
+//     firstItem: <OrderItem as CamelMirror<{ mirror_id("dto") }>>::Mirror,         // LineDto
+// }
+
+// 4. runtime_crate, 通过重新导出的路径引用snake_to_camel_runtime
+mod rt {
+    pub use snake_to_camel_runtime::*;
+}
+
+#[derive(GenCamelCase, Debug, Clone, PartialEq)]
+#[gen_camel(mirror_trait, runtime_crate = "crate::rt", derive = "Debug")]
+pub struct Coupon {
+    pub coupon_code: String,
+}
+
+// 生成的代码
+// impl crate::rt::CamelMirror for Coupon { type Mirror = CouponVo; ... }
+
+fn respond<T: CamelMirror>(t: T) -> T::Mirror {
+    t.to_mirror()
+}
+
+#[test]
+fn test_mirror_id() {
+    assert_eq!(mirror_id(""), 0);
+    assert_ne!(mirror_id("dto"), 0);
+    assert_ne!(mirror_id("dto"), mirror_id("out"));
+}
+
+#[test]
+fn test_camel_mirror_round_trip() {
+    let user = UserInfo {
+        user_id: 7,
+        user_name: "alice".to_string(),
+    };
+    let vo = respond(user.clone());
+    assert_eq!(vo.userId, 7);
+    assert_eq!(vo.userName, "alice");
+    assert_eq!(<UserInfo as CamelMirror>::from_mirror(vo), user);
+
+    let dto: UserInfoDto = CamelMirror::<{ mirror_id("dto") }>::to_mirror(user.clone());
+    assert_eq!(dto.userName, "alice");
+    assert_eq!(
+        <UserInfo as CamelMirror<{ mirror_id("dto") }>>::from_mirror(dto),
+        user
+    );
+
+    let page = Page {
+        page_items: vec![1u8, 2],
+        total_count: 2,
+    };
+    let vo = respond(page);
+    assert_eq!(vo.pageItems, vec![1, 2]);
+    assert_eq!(Page::from_mirror(vo).total_count, 2);
+}
+
+#[test]
+fn test_nested_trait() {
+    let order = Order {
+        order_items: vec![OrderItem { product_id: 1 }],
+        first_item: OrderItem { product_id: 1 },
+    };
+    let out: OrderOut = CamelMirror::<{ mirror_id("dto") }>::to_mirror(order.clone());
+    let _: &Vec<LineDto> = &out.orderItems;
+    let _: &LineDto = &out.firstItem;
+    assert_eq!(out.orderItems[0].productId, 1);
+    assert_eq!(
+        <Order as CamelMirror<{ mirror_id("dto") }>>::from_mirror(out),
+        order
+    );
+
+    let dto = respond(order.clone());
+    assert_eq!(dto.firstItem, OrderItemDto { productId: 1 });
+    assert_eq!(<Order as CamelMirror>::from_mirror(dto), order);
+}
+
+#[test]
+fn test_runtime_crate() {
+    let coupon = Coupon {
+        coupon_code: "SAVE10".to_string(),
+    };
+    let vo = respond(coupon.clone());
+    assert_eq!(vo.couponCode, "SAVE10");
+    assert_eq!(Coupon::from_mirror(vo), coupon);
+}
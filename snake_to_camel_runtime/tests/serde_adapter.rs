@@ -0,0 +1,204 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use snake_to_camel::GenCamelCase;
+use snake_to_camel_runtime::{Camel, CamelDeserializer, CamelSerializer, CaseOptions, Digits};
+use std::collections::BTreeMap;
+
+#[allow(dead_code)]
+mod fixtures;
+
+#[derive(GenCamelCase, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[gen_camel(derive = "Serialize")]
+pub struct LineItem {
+    pub item_id: u64,
+    pub unit_price: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Payment {
+    Card { card_number: String },
+    Cash(u32),
+    Unpaid,
+}
+
+#[derive(GenCamelCase, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[gen_camel(derive = "Serialize")]
+#[serde(deny_unknown_fields)]
+pub struct OrderInfo {
+    pub order_id: u64,
+    pub line_items: Vec<LineItem>,
+    #[gen_field(type_name = "LineItem")]
+    pub shipping_note: Option<LineItem>,
+    #[gen_field(type_name = "Payment")]
+    pub payment_method: Payment,
+    pub extra_counts: BTreeMap<String, u32>,
+}
+
+// 命名选项与用例表一致, acronyms与fixtures::ACRONYMS, dictionary与fixtures::DICTIONARY相同
+#[derive(GenCamelCase, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[gen_camel(
+    acronyms = "ID, URL, HTTP",
+    digits = "new_word",
+    dictionary = "../tests/names.toml",
+    derive = "Serialize"
+)]
+pub struct AccountInfo {
+    pub user_id: u64,
+    pub avatar_url: String,
+    pub oauth2token: String,
+    pub home_tel: String,
+}
+
+// #[serde(rename)]透传到镜像结构体, 适配器同样保持含大写字母的字段名不变
+#[derive(GenCamelCase, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[gen_camel(derive = "Serialize")]
+pub struct Record {
+    #[serde(rename = "ID")]
+    pub record_id: u64,
+    pub record_name: String,
+}
+
+// flatten的结构体按map序列化, 字段名保持原样
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Outer {
+    pub outer_id: u64,
+    #[serde(flatten)]
+    pub inner: Inner,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Inner {
+    pub inner_key: u64,
+}
+
+fn order() -> OrderInfo {
+    OrderInfo {
+        order_id: 1,
+        line_items: vec![LineItem {
+            item_id: 2,
+            unit_price: Some(1.5),
+        }],
+        shipping_note: None,
+        payment_method: Payment::Card {
+            card_number: "4242".to_string(),
+        },
+        extra_counts: BTreeMap::from([("snake_key".to_string(), 3)]),
+    }
+}
+
+#[test]
+fn test_camel_serializer() {
+    let order = order();
+    let value = order
+        .serialize(CamelSerializer::new(serde_json::value::Serializer))
+        .unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "orderId": 1,
+            "lineItems": [{ "itemId": 2, "unitPrice": 1.5 }],
+            "shippingNote": null,
+            "paymentMethod": { "Card": { "card_number": "4242" } },
+            "extraCounts": { "snake_key": 3 },
+        })
+    );
+    // 与派生宏生成的镜像结构体序列化结果一致, 枚举中的字段不转换
+    let vo: OrderInfoVo = order.clone().into();
+    assert_eq!(serde_json::to_value(&vo).unwrap(), value);
+    assert_eq!(serde_json::to_value(Camel(&order)).unwrap(), value);
+}
+
+#[test]
+fn test_camel_deserializer() {
+    let order = order();
+    let json = serde_json::to_string(&Camel(&order)).unwrap();
+    let Camel(parsed): Camel<OrderInfo> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, order);
+
+    let mut deserializer = serde_json::Deserializer::from_str(
+        r#"{"orderId": 1, "lineItems": [], "shippingNote": {"itemId": 5, "unitPrice": null}, "paymentMethod": "Unpaid", "extraCounts": {}}"#,
+    );
+    let parsed = OrderInfo::deserialize(CamelDeserializer::new(&mut deserializer)).unwrap();
+    assert_eq!(parsed.shipping_note.unwrap().item_id, 5);
+    assert_eq!(parsed.payment_method, Payment::Unpaid);
+
+    // 匹配不到字段的键保持原样
+    let result: Result<Camel<OrderInfo>, _> = serde_json::from_value(json!({ "unknownKey": 1 }));
+    assert!(result.unwrap_err().to_string().contains("unknownKey"));
+}
+
+#[test]
+fn test_camel_serializer_options() {
+    let account = AccountInfo {
+        user_id: 7,
+        avatar_url: "a.png".to_string(),
+        oauth2token: "t".to_string(),
+        home_tel: "123".to_string(),
+    };
+    let options = || {
+        CaseOptions::new()
+            .acronyms(fixtures::ACRONYMS)
+            .digits(Digits::NewWord)
+            .dictionary(fixtures::DICTIONARY.iter().copied())
+    };
+    let value = account
+        .serialize(CamelSerializer::with_options(
+            serde_json::value::Serializer,
+            options(),
+        ))
+        .unwrap();
+    // 与派生宏生成的镜像结构体序列化结果一致
+    let vo: AccountInfoVo = account.clone().into();
+    assert_eq!(serde_json::to_value(&vo).unwrap(), value);
+    assert_eq!(
+        value,
+        json!({
+            "userID": 7,
+            "avatarURL": "a.png",
+            "oauth2Token": "t",
+            "homePhoneNumber": "123",
+        })
+    );
+    // 默认选项与这些选项的缓存互不影响
+    let default_value = serde_json::to_value(Camel(&account)).unwrap();
+    assert_eq!(default_value["userId"], 7);
+
+    let json = value.to_string();
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    let parsed = AccountInfo::deserialize(CamelDeserializer::with_options(
+        &mut deserializer,
+        options(),
+    ))
+    .unwrap();
+    assert_eq!(parsed, account);
+}
+
+#[test]
+fn test_camel_serde_rename() {
+    let record = Record {
+        record_id: 1,
+        record_name: "a".to_string(),
+    };
+    let value = serde_json::to_value(Camel(&record)).unwrap();
+    let vo: RecordVo = record.clone().into();
+    assert_eq!(serde_json::to_value(&vo).unwrap(), value);
+    assert_eq!(value, json!({ "ID": 1, "recordName": "a" }));
+    let Camel(parsed): Camel<Record> = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed, record);
+}
+
+#[test]
+fn test_camel_serde_flatten() {
+    let outer = Outer {
+        outer_id: 1,
+        inner: Inner { inner_key: 2 },
+    };
+    let value = serde_json::to_value(Camel(&outer)).unwrap();
+    assert_eq!(value, json!({ "outer_id": 1, "inner_key": 2 }));
+    let Camel(parsed): Camel<Outer> = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed, outer);
+    let camel = json!({ "outerId": 1, "innerKey": 2 });
+    assert!(serde_json::from_value::<Camel<Outer>>(camel).is_err());
+}
@@ -0,0 +1,139 @@
+// camel_mirror函数宏: 为不能添加派生宏的外部结构体声明字段列表, 生成镜像结构体和双向From实现
+use crate::config::StructConfig;
+use crate::{expand_generate_struct, module};
+use fxhash::FxHashMap as HashMap;
+use proc_macro2::TokenStream;
+use syn::{
+    Attribute, Data, DataStruct, DeriveInput, Fields, FieldsNamed, Generics, Ident, Path, Token,
+    Visibility,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+/// 外部结构体的路径, 以及`=>`后指定的镜像结构体名称
+pub(crate) struct Remote {
+    pub(crate) path: Path,
+    name: Option<Ident>,
+}
+
+impl Remote {
+    /// 检查并合并外部结构体的配置: 只能有一个id, 不能使用patch和mirror_trait, `=>`指定的名称作为name
+    pub(crate) fn apply(
+        &self,
+        struct_config_map: &mut HashMap<String, StructConfig>,
+    ) -> syn::Result<()> {
+        if struct_config_map.len() > 1 {
+            return Err(syn::Error::new(
+                self.path.span(),
+                "camel_mirror only supports one gen_camel id",
+                // "camel_mirror 只支持一个 gen_camel id",
+            ));
+        }
+        let Some(struct_config) = struct_config_map.values_mut().next() else {
+            return Ok(());
+        };
+        // apply_patch和diff是原始结构体上的方法, 不能为外部结构体实现
+        if struct_config.patch.is_some() {
+            return Err(syn::Error::new(
+                self.path.span(),
+                "camel_mirror does not support patch",
+                // "camel_mirror 不支持 patch",
+            ));
+        }
+        // 孤儿规则不允许为外部结构体实现CamelMirror
+        if struct_config.mirror_trait.is_some() {
+            return Err(syn::Error::new(
+                self.path.span(),
+                "camel_mirror does not support mirror_trait",
+                // "camel_mirror 不支持 mirror_trait",
+            ));
+        }
+        if let Some(name) = &self.name {
+            let name_config = StructConfig {
+                id: struct_config.id.clone(),
+                name: Some(name.to_string()),
+                ..Default::default()
+            };
+            struct_config.merge(name_config, name.span())?;
+        }
+        Ok(())
+    }
+
+    /// 文档中使用的路径, 不包含空格
+    pub(crate) fn path_string(&self) -> String {
+        let segments = self
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("::");
+        if self.path.leading_colon.is_some() {
+            format!("::{}", segments)
+        } else {
+            segments
+        }
+    }
+}
+
+/// `#[gen_camel(...)] pub other_crate::Config<T> where ... { pub field_a: u32, ... } => ConfigVo`
+pub(crate) struct MirrorDecl {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    remote: Remote,
+    generics: Generics,
+    fields: FieldsNamed,
+}
+
+impl Parse for MirrorDecl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let path = input.call(Path::parse_mod_style)?;
+        let mut generics: Generics = input.parse()?;
+        generics.where_clause = input.parse()?;
+        let fields = input.parse()?;
+        let name = if input.parse::<Option<Token![=>]>>()?.is_some() {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(MirrorDecl {
+            attrs,
+            vis,
+            remote: Remote { path, name },
+            generics,
+            fields,
+        })
+    }
+}
+
+/// 展开camel_mirror: 将声明转换为派生宏的输入, 以外部结构体的路径生成代码
+pub(crate) fn expand_camel_mirror(decl: MirrorDecl) -> syn::Result<TokenStream> {
+    let MirrorDecl {
+        attrs,
+        vis,
+        remote,
+        generics,
+        fields,
+    } = decl;
+    let ident = remote
+        .path
+        .segments
+        .last()
+        .map(|segment| segment.ident.clone())
+        .unwrap();
+    let input = DeriveInput {
+        attrs,
+        vis,
+        ident,
+        generics,
+        data: Data::Struct(DataStruct {
+            struct_token: Default::default(),
+            fields: Fields::Named(fields),
+            semi_token: None,
+        }),
+    };
+    let parts = expand_generate_struct(input, Some(&remote))?;
+    module::wrap_modules(parts, false)
+}
@@ -0,0 +1,136 @@
+// camel_struct属性宏: 将snake_case声明的结构体原地改写为camelCase字段, 不生成新的结构体
+use crate::config::StructConfig;
+use crate::defaults::Defaults;
+use crate::{forward_attr, generate_builder, generate_field_names, generate_new_field_ident};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Attribute, Fields, ItemStruct, punctuated::Punctuated, spanned::Spanned};
+
+/// 展开camel_struct: 字段名按gen_camel的命名规则改为camelCase,
+/// 生成的代码使用新的字段名, 用户代码中的字段访问不做改写
+pub(crate) fn expand_camel_struct(
+    attr: TokenStream,
+    mut item_struct: ItemStruct,
+) -> syn::Result<TokenStream> {
+    let mut config = parse_config(attr)?;
+    if !matches!(item_struct.fields, Fields::Named(_)) {
+        return Err(syn::Error::new(
+            item_struct.span(),
+            "camel_struct only supports structs with named fields",
+            // "camel_struct 只支持具名字段的结构体",
+        ));
+    }
+    let mut generated_code = Vec::new();
+    // 未配置的命名选项使用snake_to_camel.toml或Cargo.toml中的默认配置
+    if let Some(defaults) = Defaults::load()? {
+        config.fill_defaults(&defaults.config);
+        if let Some(file) = defaults.file {
+            let path = file.display().to_string();
+            generated_code.push(quote! {
+                const _: &[u8] = include_bytes!(#path);
+            });
+        }
+    }
+    if let Some(dictionary) = &config.dictionary {
+        let path = &dictionary.path;
+        generated_code.push(quote! {
+            const _: &[u8] = include_bytes!(#path);
+        });
+    }
+
+    let case_options = config.case_options();
+    let renames = item_struct
+        .fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .map(|ident| {
+            (
+                ident.to_string(),
+                generate_new_field_ident(ident, &case_options).to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let doc_link_renames = config.rewrite_doc_links.map(|_| renames.as_slice());
+    rewrite_docs(&mut item_struct.attrs, doc_link_renames);
+    let mut builder_fields = Vec::new();
+    for field in item_struct.fields.iter_mut() {
+        rewrite_docs(&mut field.attrs, doc_link_renames);
+        let ident = field.ident.as_mut().unwrap();
+        *ident = generate_new_field_ident(ident, &case_options);
+        let ty = &field.ty;
+        builder_fields.push((ident.clone(), quote! { #ty }, None));
+    }
+    item_struct
+        .attrs
+        .push(syn::parse_quote!(#[allow(non_snake_case)]));
+
+    let ItemStruct {
+        ident,
+        vis,
+        generics,
+        ..
+    } = &item_struct;
+    let field_names = renames
+        .into_iter()
+        .map(|(snake, camel)| (Some(snake), camel))
+        .collect::<Vec<_>>();
+    generated_code.push(generate_field_names(ident, vis, generics, &field_names));
+    if config.builder.is_some() {
+        let build_where_clause =
+            generics
+                .where_clause
+                .clone()
+                .unwrap_or_else(|| syn::WhereClause {
+                    where_token: syn::Token![where](Span::call_site()),
+                    predicates: Punctuated::new(),
+                });
+        generated_code.push(generate_builder(
+            ident,
+            vis,
+            generics,
+            &build_where_clause,
+            &builder_fields,
+        ));
+    }
+    Ok(quote! {
+        #item_struct
+        #(#generated_code)*
+    })
+}
+
+/// 按gen_camel的语法解析参数, 只保留与字段命名和生成代码有关的配置
+fn parse_config(attr: TokenStream) -> syn::Result<StructConfig> {
+    if attr.is_empty() {
+        return Ok(StructConfig::default());
+    }
+    let span = attr.span();
+    let gen_camel: Attribute = syn::parse_quote!(#[gen_camel(#attr)]);
+    let config = StructConfig::from_attr(&gen_camel)?.unwrap_or_default();
+    let supported = StructConfig {
+        acronyms: config.acronyms.clone(),
+        digits: config.digits,
+        dictionary: config.dictionary.clone(),
+        rewrite_doc_links: config.rewrite_doc_links,
+        builder: config.builder,
+        ..Default::default()
+    };
+    if config != supported {
+        return Err(syn::Error::new(
+            span,
+            "camel_struct only supports acronyms, digits, dictionary, rewrite_doc_links, builder",
+            // "camel_struct 只支持 acronyms, digits, dictionary, rewrite_doc_links, builder",
+        ));
+    }
+    Ok(config)
+}
+
+/// 配置了rewrite_doc_links时将文档中指向snake_case字段的链接改为camelCase字段
+fn rewrite_docs(attrs: &mut [Attribute], renames: Option<&[(String, String)]>) {
+    if renames.is_none() {
+        return;
+    }
+    for attr in attrs.iter_mut() {
+        let rewritten = forward_attr(attr, renames);
+        *attr = syn::parse_quote!(#rewritten);
+    }
+}
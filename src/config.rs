@@ -1,5 +1,10 @@
+use crate::defaults::load_preset;
 use proc_macro2::Span;
-use syn::{Attribute, Ident, Lit, LitStr, Path, Type, spanned::Spanned};
+use snake_to_camel_runtime::{CaseOptions, Digits};
+use std::path::PathBuf;
+use syn::{
+    Attribute, Expr, Ident, Lit, LitBool, LitStr, Meta, Path, Type, Visibility, spanned::Spanned,
+};
 
 #[derive(Default, Clone, PartialEq)]
 pub(crate) struct StructConfig {
@@ -8,6 +13,66 @@ pub(crate) struct StructConfig {
     pub(crate) suffix: Option<String>,
     pub(crate) name: Option<String>,
     pub(crate) derives: Vec<Path>,
+    pub(crate) direction: Option<Direction>,
+    pub(crate) vis: Option<Visibility>,
+    pub(crate) attrs: Vec<Meta>,
+    pub(crate) forward_attrs: Option<ForwardAttrs>,
+    pub(crate) rewrite_doc_links: Option<bool>,
+    pub(crate) patch: Option<bool>,
+    pub(crate) diff: Option<bool>,
+    pub(crate) builder: Option<bool>,
+    pub(crate) acronyms: Vec<String>,
+    pub(crate) digits: Option<Digits>,
+    pub(crate) dictionary: Option<Dictionary>,
+    pub(crate) keep_types: Vec<String>,
+    pub(crate) type_prefix: Option<String>,
+    pub(crate) type_suffix: Option<String>,
+    pub(crate) type_map: Vec<(String, String)>,
+    pub(crate) nested: Option<Nested>,
+    pub(crate) mirror_module: Option<Path>,
+    pub(crate) module: Option<Ident>,
+    pub(crate) mirror_trait: Option<bool>,
+    pub(crate) runtime_crate: Option<Path>,
+    pub(crate) map_generics: Option<bool>,
+}
+
+/// 从dictionary文件加载的单词替换表
+#[derive(Clone, PartialEq)]
+pub(crate) struct Dictionary {
+    /// 文件的绝对路径, 生成代码中通过include_bytes!引用, 文件修改后重新编译
+    pub(crate) path: String,
+    pub(crate) words: Vec<(String, String)>,
+}
+
+/// 原始结构体和字段上的属性透传到生成结构体的规则
+#[derive(Clone, PartialEq)]
+pub(crate) enum ForwardAttrs {
+    /// 透传全部属性
+    All,
+    /// 不透传任何属性
+    None,
+    /// 只透传路径在列表中的属性
+    Only(Vec<Path>),
+}
+
+/// 嵌套类型在生成的结构体中的类型名来源
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Nested {
+    /// 按type_prefix/type_suffix或prefix/suffix推导类型名
+    Guess,
+    /// 使用嵌套类型自身派生的CamelMirror实现的Mirror关联类型
+    Trait,
+}
+
+/// 生成的转换实现方向
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Direction {
+    /// 只生成 From<原始结构体> for 新结构体
+    To,
+    /// 只生成 From<新结构体> for 原始结构体
+    From,
+    /// 同时生成两个方向的转换
+    Both,
 }
 
 #[derive(Default, Clone)]
@@ -17,6 +82,11 @@ pub(crate) struct GenFieldConfig {
     pub(crate) type_suffix: Option<String>,
     pub(crate) type_name: Option<String>,
     pub(crate) field_skip: Option<bool>,
+    pub(crate) skip_into: Option<bool>,
+    pub(crate) skip_from: Option<bool>,
+    pub(crate) default: Option<Expr>,
+    pub(crate) vis: Option<Visibility>,
+    pub(crate) attrs: Vec<Meta>,
 }
 
 #[derive(Clone)]
@@ -24,6 +94,8 @@ pub(crate) struct AddFieldConfig {
     pub(crate) id: String,
     pub(crate) ident: Ident,
     pub(crate) ty: Type,
+    pub(crate) vis: Option<Visibility>,
+    pub(crate) doc: Option<String>,
 }
 
 // pub(crate) struct OriginalFieldConfig<'a> {
@@ -35,12 +107,18 @@ impl StructConfig {
     pub(crate) fn from_attr(attr: &Attribute) -> syn::Result<Option<Self>> {
         if attr.path().is_ident("gen_camel") {
             let mut config = StructConfig::default();
+            // 是否配置了id以外的属性
+            let mut configured = false;
+            let mut presets: Vec<LitStr> = Vec::new();
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("id") {
                     let value = meta.value()?;
                     let s: LitStr = value.parse()?;
                     config.id = s.value();
-                } else if meta.path.is_ident("prefix") {
+                    return Ok(());
+                }
+                configured = true;
+                if meta.path.is_ident("prefix") {
                     let value = meta.value()?;
                     let s: Lit = value.parse()?;
                     if let Lit::Str(lit) = s {
@@ -67,25 +145,179 @@ impl StructConfig {
                         .split(',')
                         .map(|s| s.trim())
                         .filter(|s| !s.is_empty())
-                        .map(|s| syn::parse_str::<Path>(s))
+                        .map(syn::parse_str::<Path>)
                         .collect::<Result<Vec<_>, _>>()
                         .map_err(|e| meta.error(format!("Invalid derive path: {}", e)))?;//无效的 derive 路径:
-                } else {
-                    return Err(meta.error("gen_camel only supports id, name, prefix, suffix, derive"));
-                    //return Err(meta.error("gen_camel 属性只支持 id, name, prefix, suffix, derive"));
-                }
-                if config.name.is_none()
-                    && config.prefix.is_none()
-                    && config.suffix.is_none()
-                    && config.derives.is_empty()
-                {
-                    return Err(
-                        meta.error("gen_camel must specify name, prefix, suffix, derive at least one")
-                        //meta.error("gen_camel 属性必须指定 name, prefix, suffix, derive 中的一个")
+                } else if meta.path.is_ident("direction") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    config.direction = Some(match s.value().as_str() {
+                        "to" => Direction::To,
+                        "from" => Direction::From,
+                        "both" => Direction::Both,
+                        _ => {
+                            return Err(syn::Error::new(
+                                s.span(),
+                                "direction only supports \"to\", \"from\", \"both\"",
+                                // "direction 只支持 \"to\", \"from\", \"both\"",
+                            ));
+                        }
+                    });
+                } else if meta.path.is_ident("vis") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    config.vis = Some(s.parse()?);
+                } else if meta.path.is_ident("attr") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    config.attrs.push(s.parse()?);
+                } else if meta.path.is_ident("forward_attrs") {
+                    let value = meta.value()?;
+                    config.forward_attrs = Some(match value.parse::<Lit>()? {
+                        Lit::Bool(LitBool { value: true, .. }) => ForwardAttrs::All,
+                        Lit::Bool(LitBool { value: false, .. }) => ForwardAttrs::None,
+                        Lit::Str(s) => ForwardAttrs::Only(
+                            s.value()
+                                .split(',')
+                                .map(|s| s.trim())
+                                .filter(|s| !s.is_empty())
+                                .map(syn::parse_str::<Path>)
+                                .collect::<Result<Vec<_>, _>>()
+                                .map_err(|e| meta.error(format!("Invalid forward_attrs path: {}", e)))?,//无效的 forward_attrs 路径:
+                        ),
+                        lit => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "forward_attrs only supports true, false or a comma separated attribute list",
+                                // "forward_attrs 只支持 true, false 或逗号分隔的属性列表",
+                            ));
+                        }
+                    });
+                } else if meta.path.is_ident("rewrite_doc_links") {
+                    config.rewrite_doc_links = Some(true);
+                } else if meta.path.is_ident("patch") {
+                    config.patch = Some(true);
+                } else if meta.path.is_ident("diff") {
+                    config.diff = Some(true);
+                } else if meta.path.is_ident("builder") {
+                    config.builder = Some(true);
+                } else if meta.path.is_ident("acronyms") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    for acronym in s.value().split(',').map(|s| s.trim()).filter(|s| !s.is_empty()) {
+                        if !acronym.chars().all(char::is_alphanumeric) {
+                            return Err(syn::Error::new(
+                                s.span(),
+                                format!("Invalid acronym: {}", acronym),
+                                // format!("无效的缩写词: {}", acronym),
+                            ));
+                        }
+                        config.acronyms.push(acronym.to_string());
+                    }
+                } else if meta.path.is_ident("digits") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    config.digits = Some(match s.value().as_str() {
+                        "attach" => Digits::Attach,
+                        "new_word" => Digits::NewWord,
+                        "preserve" => Digits::Preserve,
+                        _ => {
+                            return Err(syn::Error::new(
+                                s.span(),
+                                "digits only supports \"attach\", \"new_word\", \"preserve\"",
+                                // "digits 只支持 \"attach\", \"new_word\", \"preserve\"",
+                            ));
+                        }
+                    });
+                } else if meta.path.is_ident("dictionary") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    config.dictionary = Some(Dictionary::load(&s)?);
+                } else if meta.path.is_ident("type_prefix") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    config.type_prefix = Some(s.value());
+                } else if meta.path.is_ident("type_suffix") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    config.type_suffix = Some(s.value());
+                } else if meta.path.is_ident("type_map") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    for entry in s.value().split(',').map(|s| s.trim()).filter(|s| !s.is_empty()) {
+                        let Some((from, to)) = entry.split_once('=') else {
+                            return Err(syn::Error::new(
+                                s.span(),
+                                format!("Invalid type_map entry: {}, expected `Type = NewType`", entry),
+                                // format!("无效的 type_map 项: {}, 应为 `Type = NewType`", entry),
+                            ));
+                        };
+                        config.push_type_mapping(from, to, s.span())?;
+                    }
+                } else if meta.path.is_ident("nested") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    config.nested = Some(match s.value().as_str() {
+                        "guess" => Nested::Guess,
+                        "trait" => Nested::Trait,
+                        _ => {
+                            return Err(syn::Error::new(
+                                s.span(),
+                                "nested only supports \"guess\", \"trait\"",
+                                // "nested 只支持 \"guess\", \"trait\"",
+                            ));
+                        }
+                    });
+                } else if meta.path.is_ident("mirror_module") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    config.mirror_module = Some(s.parse()?);
+                } else if meta.path.is_ident("module") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    config.module = Some(s.parse()?);
+                } else if meta.path.is_ident("mirror_trait") {
+                    config.mirror_trait = Some(true);
+                } else if meta.path.is_ident("runtime_crate") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    config.runtime_crate = Some(s.parse()?);
+                } else if meta.path.is_ident("map_generics") {
+                    config.map_generics = Some(true);
+                } else if meta.path.is_ident("preset") {
+                    let value = meta.value()?;
+                    presets.push(value.parse()?);
+                } else if meta.path.is_ident("keep_types") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    config.keep_types.extend(
+                        s.value()
+                            .split(',')
+                            .map(|s| s.trim())
+                            .filter(|s| !s.is_empty())
+                            .map(str::to_string),
                     );
+                } else {
+                    return Err(meta.error("gen_camel only supports id, name, prefix, suffix, derive, direction, vis, attr, forward_attrs, rewrite_doc_links, patch, diff, builder, acronyms, digits, dictionary, keep_types, type_prefix, type_suffix, type_map, nested, mirror_module, module, mirror_trait, runtime_crate, map_generics, preset"));
+                    //return Err(meta.error("gen_camel 属性只支持 id, name, prefix, suffix, derive, direction, vis, attr, forward_attrs, rewrite_doc_links, patch, diff, builder, acronyms, digits, dictionary, keep_types, type_prefix, type_suffix, type_map, nested, mirror_module, module, mirror_trait, runtime_crate, map_generics, preset"));
                 }
                 Ok(())
             })?;
+            if !configured {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "gen_camel must specify at least one option besides id",
+                    // "gen_camel 属性必须指定id以外的至少一个配置",
+                ));
+            }
+            // 以预设为基础合并显式配置, 值不同时报错
+            for preset in presets {
+                let mut preset_config = load_preset(&preset)?;
+                preset_config.id = config.id.clone();
+                preset_config.merge(config, attr.span())?;
+                config = preset_config;
+            }
+            config.validate(attr.span())?;
             return Ok(Some(config));
         }
         Ok(None)
@@ -130,12 +362,449 @@ impl StructConfig {
             }
             self.name = Some(name);
         }
-        // 合并derives
-        self.derives.extend(new_config.derives);
+        // 检查并合并direction
+        if let Some(direction) = new_config.direction {
+            if self.direction.is_some() && self.direction != Some(direction) {
+                return Err(syn::Error::new(
+                    span,
+                    "direction redefined with different values",
+                    // "gen_camel 的 direction 属性重复定义且值不同",
+                ));
+            }
+            self.direction = Some(direction);
+        }
+        // 检查并合并vis
+        if let Some(vis) = new_config.vis {
+            if self.vis.is_some() && self.vis.as_ref() != Some(&vis) {
+                return Err(syn::Error::new(
+                    span,
+                    "vis redefined with different values",
+                    // "gen_camel 的 vis 属性重复定义且值不同",
+                ));
+            }
+            self.vis = Some(vis);
+        }
+        // 检查并合并forward_attrs
+        if let Some(forward_attrs) = new_config.forward_attrs {
+            if self.forward_attrs.is_some() && self.forward_attrs.as_ref() != Some(&forward_attrs) {
+                return Err(syn::Error::new(
+                    span,
+                    "forward_attrs redefined with different values",
+                    // "gen_camel 的 forward_attrs 属性重复定义且值不同",
+                ));
+            }
+            self.forward_attrs = Some(forward_attrs);
+        }
+        if new_config.rewrite_doc_links.is_some() {
+            self.rewrite_doc_links = new_config.rewrite_doc_links;
+        }
+        if new_config.patch.is_some() {
+            self.patch = new_config.patch;
+        }
+        if new_config.diff.is_some() {
+            self.diff = new_config.diff;
+        }
+        if new_config.builder.is_some() {
+            self.builder = new_config.builder;
+        }
+        if new_config.mirror_trait.is_some() {
+            self.mirror_trait = new_config.mirror_trait;
+        }
+        if new_config.map_generics.is_some() {
+            self.map_generics = new_config.map_generics;
+        }
+        // 检查并合并acronyms
+        if !new_config.acronyms.is_empty() {
+            if !self.acronyms.is_empty() && self.acronyms != new_config.acronyms {
+                return Err(syn::Error::new(
+                    span,
+                    "acronyms redefined with different values",
+                    // "gen_camel 的 acronyms 属性重复定义且值不同",
+                ));
+            }
+            self.acronyms = new_config.acronyms;
+        }
+        // 检查并合并digits
+        if let Some(digits) = new_config.digits {
+            if self.digits.is_some() && self.digits != Some(digits) {
+                return Err(syn::Error::new(
+                    span,
+                    "digits redefined with different values",
+                    // "gen_camel 的 digits 属性重复定义且值不同",
+                ));
+            }
+            self.digits = Some(digits);
+        }
+        // 检查并合并nested
+        if let Some(nested) = new_config.nested {
+            if self.nested.is_some() && self.nested != Some(nested) {
+                return Err(syn::Error::new(
+                    span,
+                    "nested redefined with different values",
+                    // "gen_camel 的 nested 属性重复定义且值不同",
+                ));
+            }
+            self.nested = Some(nested);
+        }
+        // 检查并合并mirror_module
+        if let Some(mirror_module) = new_config.mirror_module {
+            if self.mirror_module.is_some() && self.mirror_module.as_ref() != Some(&mirror_module) {
+                return Err(syn::Error::new(
+                    span,
+                    "mirror_module redefined with different values",
+                    // "gen_camel 的 mirror_module 属性重复定义且值不同",
+                ));
+            }
+            self.mirror_module = Some(mirror_module);
+        }
+        // 检查并合并module
+        if let Some(module) = new_config.module {
+            if self.module.is_some() && self.module.as_ref() != Some(&module) {
+                return Err(syn::Error::new(
+                    span,
+                    "module redefined with different values",
+                    // "gen_camel 的 module 属性重复定义且值不同",
+                ));
+            }
+            self.module = Some(module);
+        }
+        // 检查并合并runtime_crate
+        if let Some(runtime_crate) = new_config.runtime_crate {
+            if self.runtime_crate.is_some() && self.runtime_crate.as_ref() != Some(&runtime_crate) {
+                return Err(syn::Error::new(
+                    span,
+                    "runtime_crate redefined with different values",
+                    // "gen_camel 的 runtime_crate 属性重复定义且值不同",
+                ));
+            }
+            self.runtime_crate = Some(runtime_crate);
+        }
+        // 检查并合并dictionary
+        if let Some(dictionary) = new_config.dictionary {
+            if self.dictionary.is_some() && self.dictionary.as_ref() != Some(&dictionary) {
+                return Err(syn::Error::new(
+                    span,
+                    "dictionary redefined with different values",
+                    // "gen_camel 的 dictionary 属性重复定义且值不同",
+                ));
+            }
+            self.dictionary = Some(dictionary);
+        }
+        // 检查并合并type_prefix
+        if let Some(type_prefix) = new_config.type_prefix {
+            if self.type_prefix.is_some() && self.type_prefix.as_ref() != Some(&type_prefix) {
+                return Err(syn::Error::new(
+                    span,
+                    "type_prefix redefined with different values",
+                    // "gen_camel 的 type_prefix 属性重复定义且值不同",
+                ));
+            }
+            self.type_prefix = Some(type_prefix);
+        }
+        // 检查并合并type_suffix
+        if let Some(type_suffix) = new_config.type_suffix {
+            if self.type_suffix.is_some() && self.type_suffix.as_ref() != Some(&type_suffix) {
+                return Err(syn::Error::new(
+                    span,
+                    "type_suffix redefined with different values",
+                    // "gen_camel 的 type_suffix 属性重复定义且值不同",
+                ));
+            }
+            self.type_suffix = Some(type_suffix);
+        }
+        // 合并type_map, 同一个类型映射到不同的类型名时报错
+        for (from, to) in new_config.type_map {
+            match self.type_map.iter().find(|(existing, _)| *existing == from) {
+                Some((_, existing)) if *existing != to => {
+                    return Err(syn::Error::new(
+                        span,
+                        "type_map redefined with different values",
+                        // "gen_camel 的 type_map 属性重复定义且值不同",
+                    ));
+                }
+                Some(_) => {}
+                None => self.type_map.push((from, to)),
+            }
+        }
+        // 合并derives, attrs和keep_types, 跳过重复的derive
+        for derive in new_config.derives {
+            if !self.derives.contains(&derive) {
+                self.derives.push(derive);
+            }
+        }
+        self.attrs.extend(new_config.attrs);
+        self.keep_types.extend(new_config.keep_types);
+        self.validate(span)
+    }
+
+    /// 用默认配置填充未配置的项, 显式配置优先, derive, keep_types和type_map与默认值合并
+    pub(crate) fn fill_defaults(&mut self, defaults: &StructConfig) {
+        // name, prefix和suffix共同决定结构体名, 显式配置了其中任意一项时不使用默认的prefix和suffix
+        if self.name.is_none() && self.prefix.is_none() && self.suffix.is_none() {
+            self.prefix = defaults.prefix.clone();
+            self.suffix = defaults.suffix.clone();
+        }
+        if self.acronyms.is_empty() {
+            self.acronyms = defaults.acronyms.clone();
+        }
+        if self.digits.is_none() {
+            self.digits = defaults.digits;
+        }
+        if self.nested.is_none() {
+            self.nested = defaults.nested;
+        }
+        if self.mirror_module.is_none() {
+            self.mirror_module = defaults.mirror_module.clone();
+        }
+        if self.runtime_crate.is_none() {
+            self.runtime_crate = defaults.runtime_crate.clone();
+        }
+        if self.dictionary.is_none() {
+            self.dictionary = defaults.dictionary.clone();
+        }
+        if self.type_prefix.is_none() && self.type_suffix.is_none() {
+            self.type_prefix = defaults.type_prefix.clone();
+            self.type_suffix = defaults.type_suffix.clone();
+        }
+        for (from, to) in &defaults.type_map {
+            if !self.type_map.iter().any(|(existing, _)| existing == from) {
+                self.type_map.push((from.clone(), to.clone()));
+            }
+        }
+        // 默认的derive在前, 跳过已经显式配置的derive
+        let mut derives = defaults
+            .derives
+            .iter()
+            .filter(|derive| !self.derives.contains(derive))
+            .cloned()
+            .collect::<Vec<_>>();
+        derives.append(&mut self.derives);
+        self.derives = derives;
+        for keep_type in &defaults.keep_types {
+            if !self.keep_types.contains(keep_type) {
+                self.keep_types.push(keep_type.clone());
+            }
+        }
+    }
+
+    /// 类型是否在keep_types中, 按类型路径的最后一段匹配
+    pub(crate) fn keeps_type(&self, ident: &Ident) -> bool {
+        self.keep_types.iter().any(|keep_type| {
+            keep_type.rsplit("::").next().map(str::trim) == Some(ident.to_string().as_str())
+        })
+    }
+
+    /// 添加一项type_map, 键按路径的最后一段匹配, 值必须是合法的类型名
+    pub(crate) fn push_type_mapping(
+        &mut self,
+        from: &str,
+        to: &str,
+        span: Span,
+    ) -> syn::Result<()> {
+        let from = from.trim().rsplit("::").next().unwrap_or_default().trim();
+        let to = to.trim();
+        for name in [from, to] {
+            if syn::parse_str::<Ident>(name).is_err() {
+                return Err(syn::Error::new(
+                    span,
+                    format!("Invalid type_map type name: {}", name),
+                    // format!("无效的 type_map 类型名: {}", name),
+                ));
+            }
+        }
+        self.type_map.push((from.to_string(), to.to_string()));
+        Ok(())
+    }
+
+    /// type_map中类型对应的新类型名
+    pub(crate) fn mapped_type(&self, ident: &Ident) -> Option<&str> {
+        self.type_map
+            .iter()
+            .find(|(from, _)| ident == from)
+            .map(|(_, to)| to.as_str())
+    }
+
+    /// 推导嵌套类型名时使用的前缀和后缀
+    ///
+    /// 配置了type_prefix或type_suffix时只使用这两项, 否则与生成结构体名时的规则相同:
+    /// 使用prefix和suffix, 两者都未配置时后缀为"Vo"。name只影响结构体本身。
+    pub(crate) fn type_affixes(&self) -> (&str, &str) {
+        if self.type_prefix.is_some() || self.type_suffix.is_some() {
+            (
+                self.type_prefix.as_deref().unwrap_or(""),
+                self.type_suffix.as_deref().unwrap_or(""),
+            )
+        } else {
+            (
+                self.prefix.as_deref().unwrap_or(""),
+                self.suffix
+                    .as_deref()
+                    .unwrap_or(if self.prefix.is_none() { "Vo" } else { "" }),
+            )
+        }
+    }
+
+    /// 生成代码中引用snake_to_camel_runtime的路径, 默认为`::snake_to_camel_runtime`
+    pub(crate) fn runtime_crate(&self) -> Path {
+        self.runtime_crate
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(::snake_to_camel_runtime))
+    }
+
+    /// 生成字段名使用的命名选项, 与snake_to_camel_runtime的*_with函数共用
+    pub(crate) fn case_options(&self) -> CaseOptions {
+        let mut options = CaseOptions::new()
+            .acronyms(&self.acronyms)
+            .digits(self.digits.unwrap_or_default());
+        if let Some(dictionary) = &self.dictionary {
+            options = options.dictionary(dictionary.words.iter().cloned());
+        }
+        options
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        if self.nested == Some(Nested::Trait) && self.patch.is_some() {
+            return Err(syn::Error::new(
+                span,
+                "nested = \"trait\" cannot be used with patch",
+                // "nested = \"trait\" 不能和 patch 同时使用",
+            ));
+        }
+        if self.patch.is_some() && self.direction.is_some() {
+            return Err(syn::Error::new(
+                span,
+                "patch cannot be used with direction",
+                // "patch 不能和 direction 同时使用",
+            ));
+        }
+        if self.diff.is_some() && self.patch.is_none() {
+            return Err(syn::Error::new(
+                span,
+                "diff must be used with patch",
+                // "diff 必须和 patch 同时使用",
+            ));
+        }
+        if self.builder.is_some() && self.patch.is_some() {
+            return Err(syn::Error::new(
+                span,
+                "builder cannot be used with patch",
+                // "builder 不能和 patch 同时使用",
+            ));
+        }
+        // CamelMirror需要两个方向的From实现
+        if self.mirror_trait.is_some() && (self.patch.is_some() || self.direction.is_some()) {
+            return Err(syn::Error::new(
+                span,
+                "mirror_trait cannot be used with patch or direction",
+                // "mirror_trait 不能和 patch 或 direction 同时使用",
+            ));
+        }
+        // patch模式不生成From实现
+        if self.map_generics.is_some() && self.patch.is_some() {
+            return Err(syn::Error::new(
+                span,
+                "map_generics cannot be used with patch",
+                // "map_generics 不能和 patch 同时使用",
+            ));
+        }
         Ok(())
     }
 }
 
+/// 读取CARGO_MANIFEST_DIR
+pub(crate) fn manifest_dir(span: Span) -> syn::Result<PathBuf> {
+    std::env::var("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .map_err(|_| {
+            syn::Error::new(
+                span,
+                "CARGO_MANIFEST_DIR is not set, snake_to_camel config files can only be used with cargo",
+                // "CARGO_MANIFEST_DIR 未设置, snake_to_camel 的配置文件只能在cargo中使用",
+            )
+        })
+}
+
+impl Dictionary {
+    /// 读取相对于CARGO_MANIFEST_DIR的toml文件, 文件中每个键值对为一个单词替换
+    fn load(path: &LitStr) -> syn::Result<Self> {
+        let manifest_dir = manifest_dir(path.span())?;
+        Self::load_from(&manifest_dir, &path.value(), path.span())
+    }
+
+    /// 读取相对于base_dir的dictionary文件
+    pub(crate) fn load_from(
+        base_dir: &std::path::Path,
+        path: &str,
+        span: Span,
+    ) -> syn::Result<Self> {
+        let full_path = base_dir.join(path);
+        let content = std::fs::read_to_string(&full_path).map_err(|e| {
+            syn::Error::new(
+                span,
+                format!(
+                    "failed to read dictionary file '{}': {}",
+                    full_path.display(),
+                    e
+                ),
+                // format!("读取 dictionary 文件 '{}' 失败: {}", full_path.display(), e),
+            )
+        })?;
+        let table = content.parse::<toml::Table>().map_err(|e| {
+            syn::Error::new(
+                span,
+                format!("malformed dictionary file '{}': {}", full_path.display(), e),
+                // format!("dictionary 文件 '{}' 格式错误: {}", full_path.display(), e),
+            )
+        })?;
+        let mut words = Vec::with_capacity(table.len());
+        for (word, replacement) in table {
+            let Some(replacement) = replacement.as_str() else {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "malformed dictionary file '{}': value of '{}' must be a string",
+                        // "dictionary 文件 '{}' 格式错误: '{}' 的值必须是字符串",
+                        full_path.display(),
+                        word
+                    ),
+                ));
+            };
+            // 替换后的单词会直接拼入字段名和类型名, 必须能组成合法的标识符
+            let mut chars = replacement.chars();
+            if !chars.next().is_some_and(char::is_alphabetic)
+                || !chars.all(|c| c.is_alphanumeric() || c == '_')
+            {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "invalid dictionary file '{}': replacement '{}' of '{}' must start with a letter and contain only letters, digits and underscores",
+                        // "dictionary 文件 '{}' 无效: 替换值 '{}' (键 '{}') 必须以字母开头, 只能包含字母, 数字和下划线",
+                        full_path.display(),
+                        replacement,
+                        word
+                    ),
+                ));
+            }
+            words.push((word, replacement.to_string()));
+        }
+        Ok(Dictionary {
+            path: full_path.display().to_string(),
+            words,
+        })
+    }
+}
+
+impl ForwardAttrs {
+    /// 判断原始属性是否需要透传
+    pub(crate) fn allows(&self, attr: &Attribute) -> bool {
+        match self {
+            ForwardAttrs::All => true,
+            ForwardAttrs::None => false,
+            ForwardAttrs::Only(paths) => paths.iter().any(|p| attr.path() == p),
+        }
+    }
+}
+
 impl GenFieldConfig {
     pub(crate) fn from_attr(attr: &Attribute) -> syn::Result<Option<Self>> {
         if attr.path().is_ident("gen_field") {
@@ -147,6 +816,14 @@ impl GenFieldConfig {
                         config.id = s.value();
                     } else if meta.path.is_ident("field_skip") {
                         config.field_skip = Some(true);
+                    } else if meta.path.is_ident("skip_into") {
+                        config.skip_into = Some(true);
+                    } else if meta.path.is_ident("skip_from") {
+                        config.skip_from = Some(true);
+                    } else if meta.path.is_ident("default") {
+                        let value = meta.value()?;
+                        let s: LitStr = value.parse()?;
+                        config.default = Some(s.parse()?);
                     } else if meta.path.is_ident("type_prefix") {
                         let value = meta.value()?;
                         let s: Lit = value.parse()?;
@@ -165,27 +842,21 @@ impl GenFieldConfig {
                         if let Lit::Str(lit) = s {
                             config.type_name = Some(lit.value());
                         }
+                    } else if meta.path.is_ident("vis") {
+                        let value = meta.value()?;
+                        let s: LitStr = value.parse()?;
+                        config.vis = Some(s.parse()?);
+                    } else if meta.path.is_ident("attr") {
+                        let value = meta.value()?;
+                        let s: LitStr = value.parse()?;
+                        config.attrs.push(s.parse()?);
                     } else {
-                        return Err(meta.error("gen_field only support id, field_skip, type_prefix, type_suffix, type_name"));
-                        // return Err(meta.error("gen_field 属性只支持 id, field_skip, type_prefix, type_suffix, type_name"));
-                    }
-                    if config.field_skip.is_some() {
-                        if config.type_prefix.is_some() || config.type_suffix.is_some() || config.type_name.is_some() {
-                            return Err(meta.error("field_skip cannot be used with type_prefix, type_suffix, or type_name"));
-                            // return Err(meta.error("field_skip 不能和 type_prefix, type_suffix, type_name 同时使用"));
-                        }
-                    } else {
-                        if config.type_prefix.is_none() && config.type_suffix.is_none() && config.type_name.is_none() {
-                            return Err(meta.error("gen_field must specify one of field_skip, type_prefix, type_suffix, or type_name"));
-                            // return Err(meta.error("gen_field 属性必须指定 field_skip, type_prefix, type_suffix, type_name 中的一个"));
-                        }
-                    }
-                    if config.type_name.is_some() && (config.type_prefix.is_some() || config.type_suffix.is_some()) {
-                        return Err(meta.error("type_name cannot be used with type_prefix, type_suffix"));
-                        // return Err(meta.error("gen_field 的 type_name 属性不能和 type_prefix, type_suffix 同时使用"));
+                        return Err(meta.error("gen_field only support id, field_skip, skip_into, skip_from, default, type_prefix, type_suffix, type_name, vis, attr"));
+                        // return Err(meta.error("gen_field 属性只支持 id, field_skip, skip_into, skip_from, default, type_prefix, type_suffix, type_name, vis, attr"));
                     }
                     Ok(())
                 })?;
+            config.validate(attr.span())?;
             return Ok(Some(config));
         }
         Ok(None)
@@ -236,6 +907,96 @@ impl GenFieldConfig {
             }
             self.field_skip = Some(field_skip);
         }
+        //合并skip_into
+        if let Some(skip_into) = new_config.skip_into {
+            if self.skip_into.is_some() && self.skip_into.as_ref() != Some(&skip_into) {
+                return Err(syn::Error::new(
+                    span,
+                    "skip_into redefined with different values",
+                    // format!("gen_field 的 skip_into 属性重复定义且值不同"),
+                ));
+            }
+            self.skip_into = Some(skip_into);
+        }
+        //合并skip_from
+        if let Some(skip_from) = new_config.skip_from {
+            if self.skip_from.is_some() && self.skip_from.as_ref() != Some(&skip_from) {
+                return Err(syn::Error::new(
+                    span,
+                    "skip_from redefined with different values",
+                    // format!("gen_field 的 skip_from 属性重复定义且值不同"),
+                ));
+            }
+            self.skip_from = Some(skip_from);
+        }
+        //合并default
+        if let Some(default) = new_config.default {
+            if self.default.is_some() && self.default.as_ref() != Some(&default) {
+                return Err(syn::Error::new(
+                    span,
+                    "default redefined with different values",
+                    // format!("gen_field 的 default 属性重复定义且值不同"),
+                ));
+            }
+            self.default = Some(default);
+        }
+        //合并vis
+        if let Some(vis) = new_config.vis {
+            if self.vis.is_some() && self.vis.as_ref() != Some(&vis) {
+                return Err(syn::Error::new(
+                    span,
+                    "vis redefined with different values",
+                    // format!("gen_field 的 vis 属性重复定义且值不同"),
+                ));
+            }
+            self.vis = Some(vis);
+        }
+        //合并attrs
+        self.attrs.extend(new_config.attrs);
+        self.validate(span)
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        let has_type_config =
+            self.type_prefix.is_some() || self.type_suffix.is_some() || self.type_name.is_some();
+        let has_skip =
+            self.field_skip.is_some() || self.skip_into.is_some() || self.skip_from.is_some();
+        if self.field_skip.is_some() {
+            if has_type_config || self.vis.is_some() || !self.attrs.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    "field_skip cannot be used with type_prefix, type_suffix, type_name, vis, or attr",
+                    // "field_skip 不能和 type_prefix, type_suffix, type_name, vis, attr 同时使用",
+                ));
+            }
+            if self.skip_into.is_some() || self.skip_from.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "field_skip cannot be used with skip_into or skip_from",
+                    // "field_skip 不能和 skip_into, skip_from 同时使用",
+                ));
+            }
+        } else if !has_type_config && !has_skip && self.vis.is_none() && self.attrs.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "gen_field must specify one of field_skip, skip_into, skip_from, type_prefix, type_suffix, type_name, vis, or attr",
+                // "gen_field 属性必须指定 field_skip, skip_into, skip_from, type_prefix, type_suffix, type_name, vis, attr 中的一个",
+            ));
+        }
+        if self.skip_into.is_some() && self.skip_from.is_some() {
+            return Err(syn::Error::new(
+                span,
+                "skip_into cannot be used with skip_from",
+                // "skip_into 不能和 skip_from 同时使用",
+            ));
+        }
+        if self.default.is_some() && !has_skip {
+            return Err(syn::Error::new(
+                span,
+                "default must be used with field_skip, skip_into or skip_from",
+                // "default 必须和 field_skip, skip_into, skip_from 之一同时使用",
+            ));
+        }
         if self.type_name.is_some() && (self.type_prefix.is_some() || self.type_suffix.is_some()) {
             return Err(syn::Error::new(
                 span,
@@ -253,6 +1014,8 @@ impl AddFieldConfig {
             let mut id = String::new();
             let mut field_ident = None;
             let mut field_type = None;
+            let mut vis = None;
+            let mut doc = None;
 
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("id") {
@@ -267,15 +1030,31 @@ impl AddFieldConfig {
                     let value = meta.value()?;
                     let s: LitStr = value.parse()?;
                     field_type = Some(s.parse()?);
+                } else if meta.path.is_ident("vis") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    vis = Some(s.parse()?);
+                } else if meta.path.is_ident("doc") {
+                    let value = meta.value()?;
+                    let s: LitStr = value.parse()?;
+                    doc = Some(s.value());
                 } else {
-                    return Err(meta.error("add_field only support id, field_name, field_type"));
-                    // return Err(meta.error("add_field 只支持 id、field_name 和 field_type 参数"));
+                    return Err(
+                        meta.error("add_field only support id, field_name, field_type, vis, doc")
+                    );
+                    // return Err(meta.error("add_field 只支持 id、field_name、field_type、vis 和 doc 参数"));
                 }
                 Ok(())
             })?;
             match (field_ident, field_type) {
                 (Some(ident), Some(ty)) => {
-                    return Ok(Some(AddFieldConfig { id, ident, ty }));
+                    return Ok(Some(AddFieldConfig {
+                        id,
+                        ident,
+                        ty,
+                        vis,
+                        doc,
+                    }));
                 }
                 _ => {
                     return Err(syn::Error::new(
@@ -0,0 +1,374 @@
+// 从snake_to_camel.toml或Cargo.toml的[package.metadata.snake_to_camel]读取gen_camel的默认配置和预设
+use crate::config::{Dictionary, Direction, ForwardAttrs, Nested, StructConfig, manifest_dir};
+use proc_macro2::Span;
+use snake_to_camel_runtime::Digits;
+use std::path::{Path, PathBuf};
+use syn::{LitStr, Path as SynPath};
+
+const FILE_NAME: &str = "snake_to_camel.toml";
+
+// 默认配置支持的项
+const DEFAULT_KEYS: &[&str] = &[
+    "prefix",
+    "suffix",
+    "derive",
+    "acronyms",
+    "digits",
+    "dictionary",
+    "keep_types",
+    "type_prefix",
+    "type_suffix",
+    "type_map",
+    "nested",
+    "mirror_module",
+    "runtime_crate",
+];
+
+// 预设支持的项, 除id和name外与gen_camel一致
+const PRESET_KEYS: &[&str] = &[
+    "prefix",
+    "suffix",
+    "derive",
+    "direction",
+    "vis",
+    "attr",
+    "forward_attrs",
+    "rewrite_doc_links",
+    "patch",
+    "diff",
+    "builder",
+    "acronyms",
+    "digits",
+    "dictionary",
+    "keep_types",
+    "type_prefix",
+    "type_suffix",
+    "type_map",
+    "nested",
+    "mirror_module",
+    "module",
+    "mirror_trait",
+    "runtime_crate",
+    "map_generics",
+];
+
+/// 默认配置, 以及需要在生成代码中引用以便修改后重新编译的配置文件
+pub(crate) struct Defaults {
+    pub(crate) config: StructConfig,
+    pub(crate) file: Option<PathBuf>,
+}
+
+impl Defaults {
+    /// 按以下顺序查找默认配置, 使用第一个找到的配置:
+    /// 1. CARGO_MANIFEST_DIR下的snake_to_camel.toml
+    /// 2. CARGO_MANIFEST_DIR下Cargo.toml中的[package.metadata.snake_to_camel], presets除外
+    /// 3. 上级目录中最近的snake_to_camel.toml, 最远到workspace根目录
+    pub(crate) fn load() -> syn::Result<Option<Self>> {
+        let Ok(manifest_dir) = manifest_dir(Span::call_site()) else {
+            return Ok(None);
+        };
+        let local_file = manifest_dir.join(FILE_NAME);
+        if local_file.is_file() {
+            return Self::load_file(&local_file).map(Some);
+        }
+        let manifest = manifest_dir.join("Cargo.toml");
+        if let Some(mut metadata) = read_metadata(&manifest)? {
+            metadata.remove("presets");
+            if !metadata.is_empty() {
+                return Ok(Some(Defaults {
+                    config: parse_config(&metadata, DEFAULT_KEYS, &manifest_dir, &manifest)?,
+                    file: None,
+                }));
+            }
+        }
+        // 只查找到workspace根目录为止, 不属于任何workspace的crate不使用上级目录中的配置
+        let Some(workspace_root) = workspace_root(&manifest_dir) else {
+            return Ok(None);
+        };
+        for dir in manifest_dir.ancestors().skip(1) {
+            let file = dir.join(FILE_NAME);
+            if file.is_file() {
+                return Self::load_file(&file).map(Some);
+            }
+            if dir == workspace_root {
+                break;
+            }
+        }
+        Ok(None)
+    }
+
+    fn load_file(file: &Path) -> syn::Result<Self> {
+        let table = read_table(file)?;
+        let base_dir = file.parent().unwrap_or(Path::new(""));
+        Ok(Defaults {
+            config: parse_config(&table, DEFAULT_KEYS, base_dir, file)?,
+            file: Some(file.to_path_buf()),
+        })
+    }
+}
+
+/// crate所在workspace的根目录, 即第一个Cargo.toml中包含[workspace]的上级目录。
+/// crate本身是workspace根目录, 或者是registry, git依赖和vendor目录中打包后的crate(存在Cargo.toml.orig)时返回None
+fn workspace_root(manifest_dir: &Path) -> Option<&Path> {
+    let is_workspace_root = |dir: &Path| {
+        read_table(&dir.join("Cargo.toml"))
+            .map(|table| table.contains_key("workspace"))
+            .unwrap_or(false)
+    };
+    if manifest_dir.join("Cargo.toml.orig").is_file() || is_workspace_root(manifest_dir) {
+        return None;
+    }
+    manifest_dir
+        .ancestors()
+        .skip(1)
+        .find(|dir| is_workspace_root(dir))
+}
+
+/// 读取CARGO_MANIFEST_DIR下Cargo.toml中[package.metadata.snake_to_camel.presets]定义的预设
+pub(crate) fn load_preset(name: &LitStr) -> syn::Result<StructConfig> {
+    let manifest_dir = manifest_dir(name.span())?;
+    let manifest = manifest_dir.join("Cargo.toml");
+    let preset = read_metadata(&manifest)?
+        .and_then(|metadata| metadata.get("presets").cloned())
+        .and_then(|presets| presets.get(name.value()).cloned());
+    match preset {
+        Some(toml::Value::Table(preset)) => {
+            parse_config(&preset, PRESET_KEYS, &manifest_dir, &manifest)
+        }
+        Some(_) => Err(error(
+            &manifest,
+            format!(
+                "[package.metadata.snake_to_camel.presets.{}] must be a table",
+                name.value()
+            ),
+        )),
+        None => Err(syn::Error::new(
+            name.span(),
+            format!(
+                "preset '{}' is not defined in [package.metadata.snake_to_camel.presets] of Cargo.toml",
+                // "预设 '{}' 没有在 Cargo.toml 的 [package.metadata.snake_to_camel.presets] 中定义",
+                name.value()
+            ),
+        )),
+    }
+}
+
+fn error(file: &Path, message: impl std::fmt::Display) -> syn::Error {
+    syn::Error::new(
+        Span::call_site(),
+        format!("snake_to_camel config '{}': {}", file.display(), message),
+        // format!("snake_to_camel 配置文件 '{}': {}", file.display(), message),
+    )
+}
+
+fn read_table(file: &Path) -> syn::Result<toml::Table> {
+    let content = std::fs::read_to_string(file).map_err(|e| error(file, e))?;
+    content.parse::<toml::Table>().map_err(|e| error(file, e))
+}
+
+// Cargo.toml中的[package.metadata.snake_to_camel]
+fn read_metadata(manifest: &Path) -> syn::Result<Option<toml::Table>> {
+    if !manifest.is_file() {
+        return Ok(None);
+    }
+    let table = read_table(manifest)?;
+    match table
+        .get("package")
+        .and_then(|package| package.get("metadata"))
+        .and_then(|metadata| metadata.get("snake_to_camel"))
+    {
+        Some(toml::Value::Table(metadata)) => Ok(Some(metadata.clone())),
+        Some(_) => Err(error(
+            manifest,
+            "[package.metadata.snake_to_camel] must be a table",
+        )),
+        None => Ok(None),
+    }
+}
+
+/// 解析配置表, 只接受supported中的项, dictionary的路径相对于base_dir
+fn parse_config(
+    table: &toml::Table,
+    supported: &[&str],
+    base_dir: &Path,
+    file: &Path,
+) -> syn::Result<StructConfig> {
+    let mut config = StructConfig::default();
+    for (key, value) in table {
+        if !supported.contains(&key.as_str()) {
+            return Err(error(
+                file,
+                format!(
+                    "unsupported key '{}', only supports {}",
+                    // "不支持的配置 '{}', 只支持 {}",
+                    key,
+                    supported.join(", ")
+                ),
+            ));
+        }
+        match key.as_str() {
+            "prefix" => config.prefix = Some(string(file, key, value)?),
+            "suffix" => config.suffix = Some(string(file, key, value)?),
+            "derive" => config.derives = paths(file, key, value)?,
+            "direction" => {
+                config.direction = Some(match string(file, key, value)?.as_str() {
+                    "to" => Direction::To,
+                    "from" => Direction::From,
+                    "both" => Direction::Both,
+                    _ => {
+                        return Err(error(
+                            file,
+                            "direction only supports \"to\", \"from\", \"both\"",
+                        ));
+                    }
+                });
+            }
+            "vis" => {
+                config.vis = Some(
+                    syn::parse_str(&string(file, key, value)?)
+                        .map_err(|e| error(file, format!("Invalid vis: {}", e)))?,
+                );
+            }
+            "attr" => {
+                config.attrs = string_list(file, key, value, false)?
+                    .iter()
+                    .map(|attr| {
+                        syn::parse_str(attr)
+                            .map_err(|e| error(file, format!("Invalid attr: {}", e)))
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+            }
+            "forward_attrs" => {
+                config.forward_attrs = Some(match value {
+                    toml::Value::Boolean(true) => ForwardAttrs::All,
+                    toml::Value::Boolean(false) => ForwardAttrs::None,
+                    _ => ForwardAttrs::Only(paths(file, key, value)?),
+                });
+            }
+            "rewrite_doc_links" => config.rewrite_doc_links = flag(file, key, value)?,
+            "patch" => config.patch = flag(file, key, value)?,
+            "diff" => config.diff = flag(file, key, value)?,
+            "builder" => config.builder = flag(file, key, value)?,
+            "mirror_trait" => config.mirror_trait = flag(file, key, value)?,
+            "map_generics" => config.map_generics = flag(file, key, value)?,
+            "acronyms" => config.acronyms = string_list(file, key, value, true)?,
+            "digits" => {
+                config.digits = Some(match string(file, key, value)?.as_str() {
+                    "attach" => Digits::Attach,
+                    "new_word" => Digits::NewWord,
+                    "preserve" => Digits::Preserve,
+                    _ => {
+                        return Err(error(
+                            file,
+                            "digits only supports \"attach\", \"new_word\", \"preserve\"",
+                        ));
+                    }
+                });
+            }
+            "dictionary" => {
+                config.dictionary = Some(Dictionary::load_from(
+                    base_dir,
+                    &string(file, key, value)?,
+                    Span::call_site(),
+                )?);
+            }
+            "keep_types" => config.keep_types = string_list(file, key, value, true)?,
+            "nested" => {
+                config.nested = Some(match string(file, key, value)?.as_str() {
+                    "guess" => Nested::Guess,
+                    "trait" => Nested::Trait,
+                    _ => return Err(error(file, "nested only supports \"guess\", \"trait\"")),
+                });
+            }
+            "module" => {
+                config.module = Some(
+                    syn::parse_str(&string(file, key, value)?)
+                        .map_err(|e| error(file, format!("Invalid module name: {}", e)))?,
+                );
+            }
+            "mirror_module" => {
+                config.mirror_module = Some(
+                    syn::parse_str(&string(file, key, value)?)
+                        .map_err(|e| error(file, format!("Invalid mirror_module path: {}", e)))?,
+                );
+            }
+            "runtime_crate" => {
+                config.runtime_crate = Some(
+                    syn::parse_str(&string(file, key, value)?)
+                        .map_err(|e| error(file, format!("Invalid runtime_crate path: {}", e)))?,
+                );
+            }
+            "type_prefix" => config.type_prefix = Some(string(file, key, value)?),
+            "type_suffix" => config.type_suffix = Some(string(file, key, value)?),
+            "type_map" => {
+                let Some(type_map) = value.as_table() else {
+                    return Err(error(file, "value of 'type_map' must be a table"));
+                };
+                for (from, to) in type_map {
+                    let to = string(file, &format!("type_map.{}", from), to)?;
+                    config
+                        .push_type_mapping(from, &to, Span::call_site())
+                        .map_err(|e| error(file, e))?;
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+    Ok(config)
+}
+
+fn string(file: &Path, key: &str, value: &toml::Value) -> syn::Result<String> {
+    value
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| error(file, format!("value of '{}' must be a string", key)))
+}
+
+// 开关项, 与gen_camel中只写名称相同, false表示未配置
+fn flag(file: &Path, key: &str, value: &toml::Value) -> syn::Result<Option<bool>> {
+    value
+        .as_bool()
+        .map(|value| value.then_some(true))
+        .ok_or_else(|| error(file, format!("value of '{}' must be a boolean", key)))
+}
+
+/// 字符串数组, split_commas为true时也接受逗号分隔的字符串
+fn string_list(
+    file: &Path,
+    key: &str,
+    value: &toml::Value,
+    split_commas: bool,
+) -> syn::Result<Vec<String>> {
+    let items = match value {
+        toml::Value::String(s) if split_commas => {
+            s.split(',').map(str::to_string).collect::<Vec<_>>()
+        }
+        toml::Value::String(s) => vec![s.clone()],
+        toml::Value::Array(values) => values
+            .iter()
+            .map(|value| string(file, key, value))
+            .collect::<syn::Result<Vec<_>>>()?,
+        _ => {
+            return Err(error(
+                file,
+                format!("value of '{}' must be a string or an array of strings", key),
+            ));
+        }
+    };
+    Ok(items
+        .iter()
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+fn paths(file: &Path, key: &str, value: &toml::Value) -> syn::Result<Vec<SynPath>> {
+    string_list(file, key, value, true)?
+        .iter()
+        .map(|path| {
+            syn::parse_str::<SynPath>(path)
+                .map_err(|e| error(file, format!("Invalid {} path: {}", key, e)))
+        })
+        .collect()
+}
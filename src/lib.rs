@@ -1,27 +1,139 @@
 #![forbid(unsafe_code)]
+mod camel_mirror;
+mod camel_struct;
 mod config;
+mod defaults;
+mod mirror_generics;
+mod module;
 
-use config::{AddFieldConfig, GenFieldConfig, StructConfig};
+use config::{AddFieldConfig, Direction, ForwardAttrs, GenFieldConfig, Nested, StructConfig};
+use defaults::Defaults;
 use fxhash::FxHashMap as HashMap;
 use fxhash::FxHashSet as HashSet;
-use heck::ToLowerCamelCase;
+use mirror_generics::MirrorParams;
 use proc_macro::TokenStream;
-use proc_macro2::Span;
-use quote::quote;
+use proc_macro2::{Literal, Span};
+use quote::{quote, quote_spanned};
+use snake_to_camel_runtime::{CaseOptions, Digits};
 use syn::{
-    Attribute, Data, DataStruct, DeriveInput, Field, Fields, Generics, Ident, Type, TypePath,
+    Attribute, Data, DataStruct, DeriveInput, Expr, Field, Fields, Generics, Ident, Type, TypePath,
     Visibility, WherePredicate, parse_macro_input, punctuated::Punctuated, spanned::Spanned,
 };
 
-#[proc_macro_derive(GenCamelCase, attributes(gen_camel, gen_field, add_field))]
+/// 需要转换的字段, 字段配置以及需要透传的字段属性
+type GenFieldVec<'a> = Vec<(&'a Field, Vec<GenFieldConfig>, Vec<&'a Attribute>)>;
+
+/// builder的字段名, 字段类型, 未设置时的默认值(None表示必填)
+type BuilderField = (
+    Ident,
+    proc_macro2::TokenStream,
+    Option<proc_macro2::TokenStream>,
+);
+
+#[proc_macro_derive(
+    GenCamelCase,
+    attributes(gen_camel, gen_field, add_field, gen_camel_expanded)
+)]
 pub fn derive_generate_struct(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    let expanded = input
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident(module::EXPANDED_MARKER));
     expand_derive_generate_struct(input)
+        .and_then(|parts| module::wrap_modules(parts, expanded))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// 将结构体上所有module相同的gen_camel生成的代码合并到同一个`pub mod`中
+///
+/// 同一个模块中的多个结构体使用相同的module时, 需要在外层的内联模块上使用此属性宏,
+/// 否则每个派生宏都会生成同名的模块。
+///
+/// ```ignore
+/// #[gen_camel_module]
+/// mod models {
+///     #[derive(GenCamelCase)]
+///     #[gen_camel(module = "dto")]
+///     pub struct Order { /* ... */ }
+///
+///     #[derive(GenCamelCase)]
+///     #[gen_camel(module = "dto")]
+///     pub struct OrderItem { /* ... */ }
+/// }
+/// // models::dto::OrderVo, models::dto::OrderItemVo
+/// ```
+#[proc_macro_attribute]
+pub fn gen_camel_module(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item_mod = parse_macro_input!(item as syn::ItemMod);
+    module::expand_gen_camel_module(attr.into(), item_mod)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// 将snake_case声明的结构体原地改写为camelCase字段, 不生成新的结构体
+///
+/// 参数与gen_camel相同, 只支持acronyms, digits, dictionary, rewrite_doc_links和builder。
+/// FIELD_NAMES, snake_to_camel_field和builder等生成的代码使用camelCase字段名,
+/// 用户代码中的字段访问不会被改写。
+///
+/// ```ignore
+/// #[camel_struct(acronyms = "ID", builder)]
+/// pub struct User {
+///     pub user_id: u64,
+///     pub user_name: String,
+/// }
+/// // User { userID, userName }
+/// ```
+#[proc_macro_attribute]
+pub fn camel_struct(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item_struct = parse_macro_input!(item as syn::ItemStruct);
+    camel_struct::expand_camel_struct(attr.into(), item_struct)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// 为不能添加派生宏的外部结构体声明字段列表, 生成镜像结构体和双向From实现
+///
+/// 字段列表前可以使用gen_camel, 字段上可以使用gen_field, 配置与派生宏相同, 但只能有一个id且不支持patch。
+/// `=>`后的名称等同于gen_camel的name, 省略时按prefix和suffix推导。
+///
+/// ```ignore
+/// camel_mirror!(
+///     #[gen_camel(derive = "Debug")]
+///     pub other_crate::Config {
+///         pub field_a: u32,
+///         pub field_b: String,
+///     } => ConfigVo
+/// );
+/// ```
+#[proc_macro]
+pub fn camel_mirror(input: TokenStream) -> TokenStream {
+    let decl = parse_macro_input!(input as camel_mirror::MirrorDecl);
+    camel_mirror::expand_camel_mirror(decl)
         .unwrap_or_else(|e| e.to_compile_error())
         .into()
 }
 
-fn expand_derive_generate_struct(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+/// 生成的代码, 以及配置了module时代码所在的模块名
+type GeneratedParts = Vec<(Option<Ident>, proc_macro2::TokenStream)>;
+
+fn expand_derive_generate_struct(input: DeriveInput) -> syn::Result<GeneratedParts> {
+    expand_generate_struct(input, None)
+}
+
+/// 原始结构体, camel_mirror声明的外部结构体通过路径引用
+struct OriginalStruct<'a> {
+    ident: &'a Ident,
+    // 外部结构体的路径, 派生宏的原始结构体为None
+    remote: Option<&'a camel_mirror::Remote>,
+}
+
+fn expand_generate_struct(
+    input: DeriveInput,
+    remote: Option<&camel_mirror::Remote>,
+) -> syn::Result<GeneratedParts> {
     let DeriveInput {
         attrs,
         ident: original_struct_ident,
@@ -33,33 +145,70 @@ fn expand_derive_generate_struct(input: DeriveInput) -> syn::Result<proc_macro2:
     let mut generated_code = Vec::new();
     if let Data::Struct(DataStruct { fields, .. }) = &data {
         // 处理结构体配置
-        let (struct_config_map, filtered_struct_attrs) = parse_struct_config(&attrs)?;
+        let (mut struct_config_map, filtered_struct_attrs) = parse_struct_config(&attrs)?;
+        if let Some(remote) = remote {
+            remote.apply(&mut struct_config_map)?;
+        }
+        // 未配置的项使用snake_to_camel.toml或Cargo.toml中的默认配置
+        if let Some(defaults) = Defaults::load()? {
+            for struct_config in struct_config_map.values_mut() {
+                struct_config.fill_defaults(&defaults.config);
+            }
+            // 引用默认配置文件, 文件修改后重新编译
+            if let Some(file) = defaults.file {
+                let path = file.display().to_string();
+                generated_code.push((
+                    None,
+                    quote! {
+                        const _: &[u8] = include_bytes!(#path);
+                    },
+                ));
+            }
+        }
         // 处理字段宏
         let (add_fields_map, gen_field_vec) = parse_field_config(fields, &struct_config_map)?;
+        // apply_patch方法只能生成一次
+        if struct_config_map
+            .values()
+            .filter(|config| config.patch.is_some())
+            .count()
+            > 1
+        {
+            return Err(syn::Error::new(
+                original_struct_ident.span(),
+                "only one gen_camel id can use patch",
+                // "只能有一个 gen_camel id 使用 patch",
+            ));
+        }
         // 生成代码
+        let original_struct = OriginalStruct {
+            ident: &original_struct_ident,
+            remote,
+        };
         for struct_config in struct_config_map.values() {
-            generated_code.push(generate_struct(
-                struct_config,
-                &filtered_struct_attrs,
-                &gen_field_vec,
-                &add_fields_map,
-                &original_struct_ident,
-                &generics,
-                &vis,
-            )?);
+            generated_code.push((
+                struct_config.module.clone(),
+                generate_struct(
+                    struct_config,
+                    &filtered_struct_attrs,
+                    &gen_field_vec,
+                    &add_fields_map,
+                    &original_struct,
+                    &generics,
+                    &vis,
+                )?,
+            ));
         }
     }
-    Ok(quote! {
-        #(#generated_code)*
-    })
+    Ok(generated_code)
 }
 
-fn generate_struct<'a>(
+fn generate_struct(
     struct_config: &StructConfig,
-    filtered_struct_attrs: &Vec<proc_macro2::TokenStream>,
-    gen_field_vec: &Vec<(&Field, Vec<GenFieldConfig>, Vec<&Attribute>)>,
+    filtered_struct_attrs: &[&Attribute],
+    gen_field_vec: &GenFieldVec,
     add_fields_map: &HashMap<String, Vec<AddFieldConfig>>,
-    original_struct_ident: &Ident,
+    original_struct: &OriginalStruct,
     original_struct_generics: &Generics,
     original_struct_vis: &Visibility,
 ) -> syn::Result<proc_macro2::TokenStream> {
@@ -69,39 +218,91 @@ fn generate_struct<'a>(
     let mut field_conversions = Vec::new();
     // into 实现中将新字段转换为原始字段
     let mut reverse_conversions = Vec::new();
-    // 处理from实现
+    // from实现需要的类型转换约束
     let mut from_impls = Vec::new();
-    // 处理into实现
+    // into实现需要的类型转换约束
     let mut into_impls = Vec::new();
     // 跳过字段的from实现中设定默认值
     let mut skipped_defaults = Vec::new();
+    // patch模式下apply_patch中对每个字段的赋值
+    let mut patch_applies = Vec::new();
+    // diff方法中对每个字段的比较
+    let mut patch_diffs = Vec::new();
+    // patch结构体中由原始字段生成的字段
+    let mut patch_fields = Vec::new();
+    let is_patch = struct_config.patch.is_some();
+    let is_diff = struct_config.diff.is_some();
+    // builder的字段
+    let mut builder_fields: Vec<BuilderField> = Vec::new();
+    // 新结构体的字段名, 以及由原始字段生成的字段的原始字段名
+    let mut field_names: Vec<(Option<String>, String)> = Vec::new();
 
+    let original_struct_ident = original_struct.ident;
+    // From实现中引用原始结构体的路径
+    let original_struct_path = match original_struct.remote {
+        Some(remote) => {
+            let path = &remote.path;
+            quote! { #path }
+        }
+        None => quote! { #original_struct_ident },
+    };
     let new_ident = generate_new_struct_ident(original_struct_ident, struct_config)?;
+    // 结构体可见性: gen_camel的vis优先, 否则沿用原始结构体的可见性
+    let new_struct_vis = struct_config.vis.as_ref().unwrap_or(original_struct_vis);
+    // 原始属性透传规则, 默认透传全部属性
+    let forward_attrs = struct_config
+        .forward_attrs
+        .as_ref()
+        .unwrap_or(&ForwardAttrs::All);
+    // 字段命名选项
+    let case_options = struct_config.case_options();
+    // 文档中需要重写链接的字段名
+    let doc_link_renames = struct_config.rewrite_doc_links.map(|_| {
+        gen_field_vec
+            .iter()
+            .filter_map(|(field, _, _)| field.ident.as_ref())
+            .map(|ident| {
+                (
+                    ident.to_string(),
+                    generate_new_field_ident(ident, &case_options).to_string(),
+                )
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let direction = struct_config.direction.unwrap_or(Direction::Both);
+    let emit_from = direction != Direction::From;
+    let emit_into = direction != Direction::To;
 
-    // 处理泛型和where子句
+    // 处理泛型和where子句, from和into实现分别计算约束
     let (impl_generics, ty_generics, where_clause) = original_struct_generics.split_for_impl();
-    let mut new_struct_generics = original_struct_generics.clone();
+    let new_where_clause = || {
+        where_clause.cloned().unwrap_or_else(|| syn::WhereClause {
+            where_token: syn::Token![where](Span::call_site()),
+            predicates: Punctuated::new(),
+        })
+    };
+    // 配置了map_generics时, From实现中镜像结构体使用单独的类型参数, 泛型参数可以转换为各自的镜像类型,
+    // 否则与原始结构体使用相同的类型参数, 转换时可以推导出镜像结构体的类型
+    let mirror_params = if struct_config.map_generics.is_none() {
+        MirrorParams::default()
+    } else {
+        MirrorParams::new(
+            original_struct_generics,
+            gen_field_vec.iter().map(|(field, _, _)| &field.ty),
+        )
+    };
+    let mut from_where_clause = new_where_clause();
+    let mut into_where_clause = new_where_clause();
+    let mut builder_where_clause = new_where_clause();
+    let mirror_where_predicates = mirror_params.where_predicates(original_struct_generics);
+    add_where_clauses(&mut from_where_clause, &mirror_where_predicates);
+    add_where_clauses(&mut into_where_clause, &mirror_where_predicates);
     {
-        // 先处理where子句约束
-        add_where_clauses(&mut new_struct_generics, &from_impls, &into_impls);
-
-        // 获取或创建where子句
-        let where_clause =
-            new_struct_generics
-                .where_clause
-                .get_or_insert_with(|| syn::WhereClause {
-                    where_token: syn::Token![where](Span::call_site()),
-                    predicates: Punctuated::new(),
-                });
-
         //组装字段
         for (field, field_config_vec, field_attrs) in gen_field_vec {
             let original_ident = field.ident.as_ref().unwrap();
-            let new_field_ident = Ident::new(
-                &original_ident.to_string().to_lower_camel_case(),
-                original_ident.span(),
-            );
-            let field_vis = &field.vis;
+            let new_field_ident = generate_new_field_ident(original_ident, &case_options);
             let original_ty = &field.ty;
             let mut global_field_config = None;
             let mut field_config = None;
@@ -109,7 +310,7 @@ fn generate_struct<'a>(
                 if fc.id == struct_config.id {
                     field_config = Some(fc);
                 }
-                if fc.id == "" {
+                if fc.id.is_empty() {
                     global_field_config = Some(fc);
                 }
             }
@@ -126,55 +327,173 @@ fn generate_struct<'a>(
 
             if let Some(true) = merged_config.field_skip {
                 // 添加跳过字段的默认值
-                skipped_defaults.push(quote! {
-                    #original_ident: <#original_ty as Default>::default()
-                });
-                // 添加跳过字段的Default约束（去重）
-                let predicate: WherePredicate = syn::parse_quote! { #original_ty: Default };
-                if !where_clause.predicates.iter().any(|p| p == &predicate) {
-                    where_clause.predicates.push(predicate);
+                if !is_patch {
+                    skipped_defaults.push(default_field_value(
+                        original_ident,
+                        original_ty,
+                        merged_config.default.as_ref(),
+                        &mut into_where_clause,
+                    ));
                 }
                 continue;
             }
-            if is_basic_type(&original_ty) {
-                if merged_config.type_prefix.is_some()
+            if is_basic_type(original_ty)
+                && (merged_config.type_prefix.is_some()
                     || merged_config.type_suffix.is_some()
-                    || merged_config.type_name.is_some()
-                {
-                    return Err(syn::Error::new(
-                        original_ty.span(),
-                        "Basic types cannot use type_prefix, type_suffix, or type_name",
-                        // "基础类型不能使用 type_prefix、type_suffix 或 type_name 配置",
-                    ));
-                }
+                    || merged_config.type_name.is_some())
+            {
+                return Err(syn::Error::new(
+                    original_ty.span(),
+                    "Basic types cannot use type_prefix, type_suffix, or type_name",
+                    // "基础类型不能使用 type_prefix、type_suffix 或 type_name 配置",
+                ));
             }
+            let mut field_from_impls = Vec::new();
+            let mut field_into_impls = Vec::new();
             let new_ty = transform_type(
-                &original_ty,
-                &struct_config,
-                &merged_config,
-                &mut from_impls,
-                &mut into_impls,
+                original_ty,
+                struct_config,
+                merged_config,
+                &mut field_from_impls,
+                &mut field_into_impls,
             )?;
+            mirror_params.map_from_predicates(&mut field_from_impls);
+            mirror_params.map_into_predicates(&mut field_into_impls);
+            // From实现中镜像结构体一侧的字段类型
+            let mirror_ty = mirror_params.map_type(&new_ty);
 
+            field_names.push((
+                Some(original_ident.to_string()),
+                new_field_ident.to_string(),
+            ));
+
+            // 字段可见性: gen_field的vis优先, 否则沿用原始字段的可见性
+            let field_vis = merged_config.vis.as_ref().unwrap_or(&field.vis);
+            let field_attrs = field_attrs
+                .iter()
+                .filter(|attr| forward_attrs.allows(attr))
+                .map(|attr| forward_attr(attr, doc_link_renames.as_deref()));
+            let extra_field_attrs = &merged_config.attrs;
+            let field_ty = if is_patch {
+                quote! { Option<#new_ty> }
+            } else {
+                quote! { #new_ty }
+            };
             new_fields_def.push(quote! {
                 #(#field_attrs)*
-                #field_vis #new_field_ident: #new_ty
+                #(#[#extra_field_attrs])*
+                #field_vis #new_field_ident: #field_ty
             });
 
-            if is_std_collection_type(&original_ty) {
-                field_conversions.push(quote! {
-                    #new_field_ident: original.#original_ident.into_iter().map(Into::into).collect::<#new_ty>()
-                });
-                reverse_conversions.push(quote! {
-                    #original_ident: new.#new_field_ident.into_iter().map(Into::into).collect::<#original_ty>()
-                });
+            // patch模式只生成apply_patch和diff, skip_into字段不会被应用
+            if is_patch {
+                let is_nested = is_nested_patch_type(original_ty, &new_ty, struct_config);
+                if is_diff {
+                    if is_nested {
+                        // 嵌套的patch类型递归比较
+                        patch_diffs.push(quote! {
+                            #new_field_ident: {
+                                let diff = self.#original_ident.diff(&other.#original_ident);
+                                if diff.is_empty() { None } else { Some(diff) }
+                            }
+                        });
+                    } else {
+                        from_impls.extend(field_from_impls);
+                        from_impls.push(syn::parse_quote! { #original_ty: PartialEq + Clone });
+                        let value = if is_std_collection_type(original_ty) {
+                            quote! {
+                                other.#original_ident.clone().into_iter().map(Into::into).collect::<#new_ty>()
+                            }
+                        } else {
+                            quote! { other.#original_ident.clone().into() }
+                        };
+                        patch_diffs.push(quote! {
+                            #new_field_ident: if self.#original_ident != other.#original_ident {
+                                Some(#value)
+                            } else {
+                                None
+                            }
+                        });
+                    }
+                }
+                patch_fields.push(new_field_ident.clone());
+                if merged_config.skip_into.is_some() {
+                    continue;
+                }
+                if is_nested {
+                    // 嵌套的patch类型递归应用
+                    patch_applies.push(quote! {
+                        if let Some(value) = patch.#new_field_ident {
+                            self.#original_ident.apply_patch(value);
+                        }
+                    });
+                } else if is_std_collection_type(original_ty) {
+                    into_impls.extend(field_into_impls);
+                    patch_applies.push(quote! {
+                        if let Some(value) = patch.#new_field_ident {
+                            self.#original_ident = value.into_iter().map(Into::into).collect::<#original_ty>();
+                        }
+                    });
+                } else {
+                    into_impls.extend(field_into_impls);
+                    patch_applies.push(quote! {
+                        if let Some(value) = patch.#new_field_ident {
+                            self.#original_ident = value.into();
+                        }
+                    });
+                }
+                continue;
+            }
+
+            // skip_from字段在builder中可以不设置
+            let builder_default = merged_config.skip_from.map(|_| {
+                default_value(
+                    &new_ty,
+                    merged_config.default.as_ref(),
+                    &mut builder_where_clause,
+                )
+            });
+            builder_fields.push((new_field_ident.clone(), quote! { #new_ty }, builder_default));
+
+            // skip_from: from实现中不读取原始字段, 新字段使用默认值
+            if let Some(true) = merged_config.skip_from {
+                field_conversions.push(default_field_value(
+                    &new_field_ident,
+                    &mirror_ty,
+                    merged_config.default.as_ref(),
+                    &mut from_where_clause,
+                ));
             } else {
-                field_conversions.push(quote! {
-                    #new_field_ident: original.#original_ident.into()
-                });
-                reverse_conversions.push(quote! {
-                    #original_ident: new.#new_field_ident.into()
-                });
+                from_impls.extend(field_from_impls);
+                if is_std_collection_type(original_ty) {
+                    field_conversions.push(quote! {
+                        #new_field_ident: original.#original_ident.into_iter().map(Into::into).collect::<#mirror_ty>()
+                    });
+                } else {
+                    field_conversions.push(quote! {
+                        #new_field_ident: original.#original_ident.into()
+                    });
+                }
+            }
+            // skip_into: into实现中忽略新字段, 原始字段使用默认值
+            if let Some(true) = merged_config.skip_into {
+                skipped_defaults.push(default_field_value(
+                    original_ident,
+                    original_ty,
+                    merged_config.default.as_ref(),
+                    &mut into_where_clause,
+                ));
+            } else {
+                into_impls.extend(field_into_impls);
+                if is_std_collection_type(original_ty) {
+                    reverse_conversions.push(quote! {
+                        #original_ident: new.#new_field_ident.into_iter().map(Into::into).collect::<#original_ty>()
+                    });
+                } else {
+                    reverse_conversions.push(quote! {
+                        #original_ident: new.#new_field_ident.into()
+                    });
+                }
             }
         }
         // 处理新增字段
@@ -187,19 +506,29 @@ fn generate_struct<'a>(
             for extra_field in add_fields {
                 let ident = &extra_field.ident;
                 let ty = &extra_field.ty;
+                let vis = extra_field.vis.as_ref().unwrap_or(new_struct_vis);
+                let doc = extra_field.doc.iter();
+                field_names.push((None, ident.to_string()));
                 new_fields_def.push(quote! {
-                    #original_struct_vis #ident: #ty
+                    #(#[doc = #doc])*
+                    #vis #ident: #ty
                 });
-                field_conversions.push(quote! {
-                    #ident: Default::default()
-                });
-                // 添加新增字段的Default约束（去重）
-                let predicate: WherePredicate = syn::parse_quote! { #ty: Default };
-                if !where_clause.predicates.iter().any(|p| p == &predicate) {
-                    where_clause.predicates.push(predicate);
-                }
+                field_conversions.push(default_field_value(
+                    ident,
+                    &mirror_params.map_type(ty),
+                    None,
+                    &mut from_where_clause,
+                ));
+                builder_fields.push((
+                    ident.clone(),
+                    quote! { #ty },
+                    Some(default_value(ty, None, &mut builder_where_clause)),
+                ));
             }
         }
+        // 添加类型转换约束
+        add_where_clauses(&mut from_where_clause, &from_impls);
+        add_where_clauses(&mut into_where_clause, &into_impls);
     }
 
     //生成派生宏
@@ -213,52 +542,327 @@ fn generate_struct<'a>(
     };
 
     // 生成结构体定义
-    let new_struct_generics = if new_struct_generics.params.is_empty() {
+    let new_struct_generics = if original_struct_generics.params.is_empty() {
         quote! {}
     } else {
-        quote! { #new_struct_generics }
+        quote! { #original_struct_generics }
     };
+    let filtered_struct_attrs = filtered_struct_attrs
+        .iter()
+        .filter(|attr| forward_attrs.allows(attr))
+        .collect::<Vec<_>>();
+    // 生成指向原始结构体的文档, 与透传的原始文档之间空一行
+    let mirror_doc = match original_struct.remote {
+        Some(remote) => format!(" Camel-case mirror of [`{}`].", remote.path_string()),
+        None => format!(" Camel-case mirror of [`{}`].", original_struct_ident),
+    };
+    let doc_separator = if filtered_struct_attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("doc"))
+    {
+        quote! { #[doc = ""] }
+    } else {
+        quote! {}
+    };
+    let filtered_struct_attrs = filtered_struct_attrs
+        .into_iter()
+        .map(|attr| forward_attr(attr, doc_link_renames.as_deref()));
+    let extra_struct_attrs = &struct_config.attrs;
     let new_struct = quote! {
+        #[doc = #mirror_doc]
+        #doc_separator
         #derive_attrs
         #[allow(non_snake_case, non_camel_case_types)]
         #(#filtered_struct_attrs)*
-        #original_struct_vis struct #new_ident #new_struct_generics {
+        #(#[#extra_struct_attrs])*
+        #new_struct_vis struct #new_ident #new_struct_generics {
             #(#new_fields_def,)*
         }
     };
 
-    // 生成From转换实现
-    let conversions = quote! {
-        impl #impl_generics From<#original_struct_ident #ty_generics> for #new_ident #ty_generics #where_clause {
-            fn from(original: #original_struct_ident #ty_generics) -> Self {
-                Self {
-                    #(#field_conversions,)*
+    // 字段名常量和snake_case/camelCase字段名映射
+    let field_names_impl = generate_field_names(
+        &new_ident,
+        new_struct_vis,
+        original_struct_generics,
+        &field_names,
+    );
+    // 引用dictionary文件, 文件修改后重新编译
+    let dictionary_include = struct_config.dictionary.as_ref().map(|dictionary| {
+        let path = &dictionary.path;
+        quote! {
+            const _: &[u8] = include_bytes!(#path);
+        }
+    });
+    let new_struct = quote! {
+        #new_struct
+        #field_names_impl
+        #dictionary_include
+    };
+
+    // patch模式生成apply_patch, 不生成From转换实现
+    if is_patch {
+        let diff_impl = if is_diff {
+            quote! {
+                impl #impl_generics #new_ident #ty_generics #where_clause {
+                    /// Returns `true` if no field generated from the original struct is set.
+                    #new_struct_vis fn is_empty(&self) -> bool {
+                        true #(&& self.#patch_fields.is_none())*
+                    }
+                }
+
+                impl #impl_generics #original_struct_ident #ty_generics #from_where_clause {
+                    /// Builds a patch holding only the fields that differ from `other`, with values taken from `other`.
+                    #new_struct_vis fn diff(&self, other: &Self) -> #new_ident #ty_generics {
+                        #new_ident {
+                            #(#patch_diffs,)*
+                            #(#field_conversions,)*
+                        }
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+        return Ok(quote! {
+            #new_struct
+
+            impl #impl_generics #original_struct_ident #ty_generics #into_where_clause {
+                /// Applies the fields of `patch` that are `Some`, leaving the others unchanged.
+                #new_struct_vis fn apply_patch(&mut self, patch: #new_ident #ty_generics) {
+                    #(#patch_applies)*
+                }
+            }
+
+            #diff_impl
+        });
+    }
+
+    // 生成From转换实现, 只生成direction配置的方向
+    // 外部结构体解构声明的全部字段, 上游增删字段时在声明处编译报错
+    let remote_fields_check = original_struct.remote.map(|remote| {
+        let path = &remote.path;
+        let idents = gen_field_vec
+            .iter()
+            .filter_map(|(field, _, _)| field.ident.as_ref());
+        quote_spanned! {path.span()=>
+            let #path { #(#idents: _,)* } = &original;
+        }
+    });
+    let mirror_impl_generics = mirror_params.impl_generics(original_struct_generics);
+    let (mirror_impl_generics, _, _) = mirror_impl_generics.split_for_impl();
+    let mirror_ty_generics = mirror_params.mirror_generics(original_struct_generics);
+    let (_, mirror_ty_generics, _) = mirror_ty_generics.split_for_impl();
+    let from_impl = if emit_from {
+        quote! {
+            impl #mirror_impl_generics From<#original_struct_path #ty_generics> for #new_ident #mirror_ty_generics #from_where_clause {
+                fn from(original: #original_struct_path #ty_generics) -> Self {
+                    #remote_fields_check
+                    Self {
+                        #(#field_conversions,)*
+                    }
                 }
             }
         }
+    } else {
+        quote! {}
+    };
+    let into_impl = if emit_into {
+        quote! {
+            impl #mirror_impl_generics From<#new_ident #mirror_ty_generics> for #original_struct_path #ty_generics #into_where_clause {
+                fn from(new: #new_ident #mirror_ty_generics) -> Self {
+                    Self {
+                        #(#reverse_conversions,)*
+                        #(#skipped_defaults,)*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let builder_impl = if struct_config.builder.is_some() {
+        generate_builder(
+            &new_ident,
+            new_struct_vis,
+            original_struct_generics,
+            &builder_where_clause,
+            &builder_fields,
+        )
+    } else {
+        quote! {}
+    };
+    // 配置了mirror_trait的id实现CamelMirror, 只有这些调用方需要依赖snake_to_camel_runtime
+    let mirror_impl = if struct_config.mirror_trait.is_some() {
+        // Mirror与原始结构体的类型参数相同, From实现的约束中的镜像类型参数替换回原始类型参数
+        let mut mirror_where_clause = new_where_clause();
+        for where_clause in [&from_where_clause, &into_where_clause] {
+            let mut predicates = where_clause.predicates.iter().cloned().collect::<Vec<_>>();
+            mirror_params.unmap_predicates(&mut predicates);
+            add_where_clauses(&mut mirror_where_clause, &predicates);
+        }
+        let mirror_id = snake_to_camel_runtime::mirror_id(&struct_config.id);
+        let mirror_id = Literal::u64_suffixed(mirror_id);
+        let runtime_crate = struct_config.runtime_crate();
+        quote! {
+            impl #impl_generics #runtime_crate::CamelMirror<#mirror_id> for #original_struct_ident #ty_generics #mirror_where_clause {
+                type Mirror = #new_ident #ty_generics;
 
-        impl #impl_generics From<#new_ident #ty_generics> for #original_struct_ident #ty_generics #where_clause {
-            fn from(new: #new_ident #ty_generics) -> Self {
-                Self {
-                    #(#reverse_conversions,)*
-                    #(#skipped_defaults,)*
+                fn to_mirror(self) -> Self::Mirror {
+                    From::from(self)
+                }
+
+                fn from_mirror(mirror: Self::Mirror) -> Self {
+                    From::from(mirror)
                 }
             }
         }
+    } else {
+        quote! {}
     };
     Ok(quote! {
         #new_struct
-        #conversions
+        #from_impl
+        #into_impl
+        #builder_impl
+        #mirror_impl
     })
 }
 
+/// 生成字段名常量FIELD_NAMES以及snake_case和camelCase字段名之间的映射
+fn generate_field_names(
+    new_ident: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    field_names: &[(Option<String>, String)],
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let names = field_names.iter().map(|(_, name)| name);
+    let (snake_names, camel_names): (Vec<_>, Vec<_>) = field_names
+        .iter()
+        .filter_map(|(original, name)| original.as_ref().map(|original| (original, name)))
+        .unzip();
+    quote! {
+        impl #impl_generics #new_ident #ty_generics #where_clause {
+            /// All field names of this struct.
+            #vis const FIELD_NAMES: &'static [&'static str] = &[#(#names),*];
+
+            /// Maps a snake_case field name to the field name of this struct.
+            #vis fn snake_to_camel_field(field: &str) -> Option<&'static str> {
+                match field {
+                    #(#snake_names => Some(#camel_names),)*
+                    _ => None,
+                }
+            }
+
+            /// Maps a field name of this struct back to its snake_case field name.
+            #vis fn camel_to_snake_field(field: &str) -> Option<&'static str> {
+                match field {
+                    #(#camel_names => Some(#snake_names),)*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// 生成新结构体的builder, 未设置的必填字段在build时返回错误
+fn generate_builder(
+    new_ident: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    build_where_clause: &syn::WhereClause,
+    builder_fields: &[BuilderField],
+) -> proc_macro2::TokenStream {
+    let builder_ident = Ident::new(&format!("{}Builder", new_ident), new_ident.span());
+    let builder_doc = format!(" Builder of [`{}`].", new_ident);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let struct_generics = if generics.params.is_empty() {
+        quote! {}
+    } else {
+        quote! { #generics }
+    };
+    let idents = builder_fields
+        .iter()
+        .map(|(ident, _, _)| ident)
+        .collect::<Vec<_>>();
+    let tys = builder_fields
+        .iter()
+        .map(|(_, ty, _)| ty)
+        .collect::<Vec<_>>();
+    let required = builder_fields
+        .iter()
+        .filter(|(_, _, default)| default.is_none())
+        .map(|(ident, _, _)| ident)
+        .collect::<Vec<_>>();
+    let required_names = required.iter().map(|ident| ident.to_string());
+    let optional = builder_fields
+        .iter()
+        .filter_map(|(ident, _, default)| default.as_ref().map(|default| (ident, default)))
+        .collect::<Vec<_>>();
+    let optional_idents = optional.iter().map(|(ident, _)| ident);
+    let optional_defaults = optional.iter().map(|(_, default)| default);
+    let setter_docs = idents
+        .iter()
+        .map(|ident| format!(" Sets [`{}::{}`].", new_ident, ident));
+    quote! {
+        #[doc = #builder_doc]
+        #[allow(non_snake_case)]
+        #vis struct #builder_ident #struct_generics {
+            #(#idents: Option<#tys>,)*
+        }
+
+        impl #impl_generics Default for #builder_ident #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#idents: None,)*
+                }
+            }
+        }
+
+        impl #impl_generics #new_ident #ty_generics #where_clause {
+            /// Creates a builder with no field set.
+            #vis fn builder() -> #builder_ident #ty_generics {
+                Default::default()
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl #impl_generics #builder_ident #ty_generics #where_clause {
+            #(
+                #[doc = #setter_docs]
+                #vis fn #idents(mut self, value: impl Into<#tys>) -> Self {
+                    self.#idents = Some(value.into());
+                    self
+                }
+            )*
+        }
+
+        impl #impl_generics #builder_ident #ty_generics #build_where_clause {
+            /// Builds the struct, returning an error that lists the missing required fields.
+            #vis fn build(self) -> Result<#new_ident #ty_generics, String> {
+                let mut missing: Vec<&'static str> = Vec::new();
+                #(
+                    if self.#required.is_none() {
+                        missing.push(#required_names);
+                    }
+                )*
+                if !missing.is_empty() {
+                    return Err(format!("missing required fields: {}", missing.join(", ")));
+                }
+                Ok(#new_ident {
+                    #(#required: self.#required.unwrap(),)*
+                    #(#optional_idents: self.#optional_idents.unwrap_or_else(|| #optional_defaults),)*
+                })
+            }
+        }
+    }
+}
+
 fn parse_field_config<'a>(
     fields: &'a Fields,
     struct_config_map: &HashMap<String, StructConfig>,
-) -> syn::Result<(
-    HashMap<String, Vec<AddFieldConfig>>,
-    Vec<(&'a Field, Vec<GenFieldConfig>, Vec<&'a Attribute>)>,
-)> {
+) -> syn::Result<(HashMap<String, Vec<AddFieldConfig>>, GenFieldVec<'a>)> {
     let mut add_field_map: HashMap<String, Vec<AddFieldConfig>> = HashMap::default();
     let mut gen_field_vec = Vec::new();
     for field in fields.iter() {
@@ -270,7 +874,7 @@ fn parse_field_config<'a>(
         let mut field_attrs: Vec<&'a Attribute> = Vec::new();
         for attr in &field.attrs {
             // 解析新增的字段
-            if let Some(add_field) = AddFieldConfig::from_attr(&attr)? {
+            if let Some(add_field) = AddFieldConfig::from_attr(attr)? {
                 // 校验extra_field.id的有效性
                 if !struct_config_map.contains_key(&add_field.id) {
                     return Err(syn::Error::new(
@@ -288,7 +892,7 @@ fn parse_field_config<'a>(
             }
             // 解析转换的字段
             if let Some(_iden) = &field.ident {
-                if let Some(field_config) = GenFieldConfig::from_attr(&attr)? {
+                if let Some(field_config) = GenFieldConfig::from_attr(attr)? {
                     // 校验field_config.id的有效性
                     if !struct_config_map.contains_key(&field_config.id) {
                         return Err(syn::Error::new(
@@ -312,7 +916,7 @@ fn parse_field_config<'a>(
                 } else if attr.path().is_ident("gen_camel") {
                     return Err(syn::Error::new(
                         attr.span(),
-                        format!("gen_camel can't use in field"),
+                        "gen_camel can't use in field",
                         // format!("gen_camel 不能用在字段上"),
                     ));
                 } else {
@@ -321,7 +925,7 @@ fn parse_field_config<'a>(
             } else {
                 return Err(syn::Error::new(
                     attr.span(),
-                    format!("gen_field can't use in unnamed field"),
+                    "gen_field can't use in unnamed field",
                     // format!("gen_field 配置的字段不是命名字段"),
                 ));
             }
@@ -334,10 +938,10 @@ fn parse_field_config<'a>(
 }
 
 fn parse_struct_config(
-    attrs: &Vec<Attribute>,
-) -> syn::Result<(HashMap<String, StructConfig>, Vec<proc_macro2::TokenStream>)> {
+    attrs: &[Attribute],
+) -> syn::Result<(HashMap<String, StructConfig>, Vec<&Attribute>)> {
     let mut struct_config_map: HashMap<String, StructConfig> = HashMap::default();
-    let mut filtered_attrs: Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut filtered_attrs: Vec<&Attribute> = Vec::new();
     // 处理结构体宏
     for attr in attrs {
         // 解析struct_config
@@ -364,8 +968,10 @@ fn parse_struct_config(
                 "gen_field can't use in struct",
                 // format!("gen_field 不能用在 struct 上"),
             ));
-        } else if !attr.path().is_ident("gen_camel") {
-            filtered_attrs.push(quote! { #attr });
+        } else if !attr.path().is_ident("gen_camel")
+            && !attr.path().is_ident(module::EXPANDED_MARKER)
+        {
+            filtered_attrs.push(attr);
         }
     }
     if struct_config_map.is_empty() {
@@ -392,6 +998,7 @@ fn generate_new_struct_ident(original: &Ident, config: &StructConfig) -> syn::Re
         ident_str.push_str(prefix);
         ident_str.push_str(&original_str);
         ident_str.push_str(suffix);
+        let ident_str = apply_digits_to_type_name(ident_str, config);
 
         if ident_str.is_empty() {
             return Err(syn::Error::new(
@@ -404,33 +1011,165 @@ fn generate_new_struct_ident(original: &Ident, config: &StructConfig) -> syn::Re
     }
 }
 
-fn transform_type<'a>(
+/// digits = "new_word"时, 推导出的类型名中数字后的字母大写(`Oauth2tokenVo` -> `Oauth2TokenVo`), 与字段名的单词边界一致,
+/// 其他规则下类型名没有分隔符, 保持不变
+fn apply_digits_to_type_name(name: String, config: &StructConfig) -> String {
+    if config.digits != Some(Digits::NewWord) {
+        return name;
+    }
+    let mut new_name = String::with_capacity(name.len());
+    let mut prev_numeric = false;
+    for c in name.chars() {
+        if prev_numeric {
+            new_name.extend(c.to_uppercase());
+        } else {
+            new_name.push(c);
+        }
+        prev_numeric = c.is_numeric();
+    }
+    new_name
+}
+
+/// 嵌套类型在生成的结构体中的类型
+enum NewTypeName {
+    /// 替换路径最后一段的类型名
+    Renamed(Ident),
+    /// keep_types中的类型保持不变
+    Kept,
+    /// nested = "trait"时使用`<T as CamelMirror<ID>>::Mirror`
+    Mirror,
+}
+
+/// 推导嵌套类型在生成的结构体中的类型名
+///
+/// 按以下顺序使用第一个匹配的配置:
+/// 1. 字段的type_name
+/// 2. 字段的type_prefix/type_suffix
+/// 3. 结构体的type_map
+/// 4. 结构体的keep_types(保持原类型)
+/// 5. 结构体的nested = "trait"(使用嵌套类型的CamelMirror::Mirror)
+/// 6. 结构体的type_prefix/type_suffix
+/// 7. 结构体的prefix/suffix, 两者都未配置时为"Vo"后缀
+fn generate_new_type_ident(
+    ident: &Ident,
+    struct_config: &StructConfig,
+    field_config: &GenFieldConfig,
+) -> NewTypeName {
+    if let Some(name) = &field_config.type_name {
+        return NewTypeName::Renamed(Ident::new(name, ident.span()));
+    }
+    let (prefix, suffix) =
+        if field_config.type_prefix.is_some() || field_config.type_suffix.is_some() {
+            (
+                field_config.type_prefix.as_deref().unwrap_or(""),
+                field_config.type_suffix.as_deref().unwrap_or(""),
+            )
+        } else if let Some(name) = struct_config.mapped_type(ident) {
+            return NewTypeName::Renamed(Ident::new(name, ident.span()));
+        } else if struct_config.keeps_type(ident) {
+            return NewTypeName::Kept;
+        } else if struct_config.nested == Some(Nested::Trait) {
+            return NewTypeName::Mirror;
+        } else {
+            struct_config.type_affixes()
+        };
+    let ident_str = format!("{}{}{}", prefix, ident, suffix);
+    let ident_str = apply_digits_to_type_name(ident_str, struct_config);
+    NewTypeName::Renamed(Ident::new(&ident_str, ident.span()))
+}
+
+fn generate_new_field_ident(original: &Ident, case_options: &CaseOptions) -> Ident {
+    // 与snake_to_camel_runtime::to_camel_with共用同一套命名规则
+    Ident::new(
+        &snake_to_camel_runtime::to_camel_with(&original.to_string(), case_options),
+        original.span(),
+    )
+}
+
+/// 透传原始属性, 配置了rewrite_doc_links时将文档中指向snake_case字段的链接改为camelCase字段
+fn forward_attr(
+    attr: &Attribute,
+    renames: Option<&[(String, String)]>,
+) -> proc_macro2::TokenStream {
+    if let (Some(renames), syn::Meta::NameValue(name_value)) = (renames, &attr.meta)
+        && let Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(doc),
+            ..
+        }) = &name_value.value
+        && name_value.path.is_ident("doc")
+    {
+        let doc = rewrite_doc_links(&doc.value(), renames);
+        return quote! { #[doc = #doc] };
+    }
+    quote! { #attr }
+}
+
+/// 重写文档中的 [`field`], [field] 和 Self::field 形式的链接
+fn rewrite_doc_links(doc: &str, renames: &[(String, String)]) -> String {
+    let mut doc = doc.to_string();
+    for (from, to) in renames {
+        if from == to {
+            continue;
+        }
+        doc = doc
+            .replace(&format!("[`{}`]", from), &format!("[`{}`]", to))
+            .replace(&format!("[{}]", from), &format!("[{}]", to));
+        // Self::field 需要检查字段名后面不是标识符字符
+        let pattern = format!("Self::{}", from);
+        let mut rewritten = String::with_capacity(doc.len());
+        let mut rest = doc.as_str();
+        while let Some(pos) = rest.find(&pattern) {
+            let end = pos + pattern.len();
+            let boundary = !rest[end..]
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+            rewritten.push_str(&rest[..pos]);
+            if boundary {
+                rewritten.push_str("Self::");
+                rewritten.push_str(to);
+            } else {
+                rewritten.push_str(&pattern);
+            }
+            rest = &rest[end..];
+        }
+        rewritten.push_str(rest);
+        doc = rewritten;
+    }
+    doc
+}
+
+fn transform_type(
     ty: &Type,
     struct_config: &StructConfig,
     field_config: &GenFieldConfig,
     from_impls: &mut Vec<WherePredicate>,
     into_impls: &mut Vec<WherePredicate>,
 ) -> syn::Result<Type> {
-    let result = match ty {
+    match ty {
+        // <T as Trait>::Assoc这样的限定路径无法推导镜像类型, 保持不变
+        Type::Path(type_path) if type_path.qself.is_some() => Ok(ty.clone()),
+
         Type::Path(_) if is_basic_type(ty) => Ok(ty.clone()),
 
+        // patch结构体中的嵌套类型无法由其patch结构体转换回来, 容器中的类型保持不变, 字段整体替换
+        _ if struct_config.patch.is_some()
+            && (is_std_collection_type(ty)
+                || is_smart_pointer_type(ty)
+                || matches!(ty, Type::Reference(_) | Type::Array(_) | Type::Tuple(_))) =>
+        {
+            Ok(ty.clone())
+        }
+
         Type::Path(type_path) if is_std_collection_type(ty) => {
             let mut new_path = type_path.path.clone();
-            for segment in &mut new_path.segments {
-                if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
-                    for arg in &mut args.args {
-                        if let syn::GenericArgument::Type(ty) = arg {
-                            *ty = transform_type(
-                                ty,
-                                struct_config,
-                                field_config,
-                                from_impls,
-                                into_impls,
-                            )?;
-                        }
-                    }
-                }
-            }
+            transform_generic_args(
+                &mut new_path,
+                struct_config,
+                field_config,
+                from_impls,
+                into_impls,
+            )?;
             Ok(Type::Path(TypePath {
                 qself: type_path.qself.clone(),
                 path: new_path,
@@ -441,21 +1180,13 @@ fn transform_type<'a>(
         Type::Path(type_path) if is_smart_pointer_type(ty) => {
             let mut new_path = type_path.path.clone();
             // 递归转换内部泛型参数类型
-            for segment in &mut new_path.segments {
-                if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
-                    for arg in &mut args.args {
-                        if let syn::GenericArgument::Type(ty) = arg {
-                            *ty = transform_type(
-                                ty,
-                                struct_config,
-                                field_config,
-                                from_impls,
-                                into_impls,
-                            )?;
-                        }
-                    }
-                }
-            }
+            transform_generic_args(
+                &mut new_path,
+                struct_config,
+                field_config,
+                from_impls,
+                into_impls,
+            )?;
             Ok(Type::Path(TypePath {
                 qself: type_path.qself.clone(),
                 path: new_path,
@@ -507,51 +1238,66 @@ fn transform_type<'a>(
             // 克隆原始路径以避免临时值问题
             let mut new_path = type_path.path.clone();
 
+            // nested = "trait"时使用嵌套类型的CamelMirror::Mirror
+            let mut mirror = false;
+            // 是否替换了类型名, 只有替换后的类型名会被移动到mirror_module中
+            let mut renamed = false;
             if let Some(last) = new_path.segments.last_mut() {
                 let ident = &last.ident;
                 let ident_str = ident.to_string();
                 // 检查是否为泛型参数（单个大写字母）
-                let new_ident = if ident_str.len() == 1
-                    && ident_str.chars().next().unwrap().is_uppercase()
-                {
-                    // 保留原始泛型参数名称
-                    ident.clone()
-                } else if let Some(name) = &field_config.type_name {
-                    Ident::new(name, ident.span())
-                } else {
-                    if field_config.type_prefix.is_none() && field_config.type_suffix.is_none() {
-                        let prefix = struct_config.prefix.as_deref().unwrap_or("");
-                        let suffix = struct_config.suffix.as_deref().unwrap_or("Vo");
-                        // 预分配字符串
-                        let mut ident_str = String::with_capacity(
-                            prefix.len() + ident.to_string().len() + suffix.len(),
-                        );
-                        ident_str.push_str(prefix);
-                        ident_str.push_str(&ident.to_string());
-                        ident_str.push_str(suffix);
-                        Ident::new(&ident_str, ident.span())
+                let new_ident =
+                    if ident_str.len() == 1 && ident_str.chars().next().unwrap().is_uppercase() {
+                        // 保留原始泛型参数名称
+                        ident.clone()
                     } else {
-                        let prefix = field_config.type_prefix.as_deref().unwrap_or("");
-                        let suffix = field_config.type_suffix.as_deref().unwrap_or("");
-                        // 预分配字符串
-                        let mut ident_str = String::with_capacity(
-                            prefix.len() + ident.to_string().len() + suffix.len(),
-                        );
-                        ident_str.push_str(prefix);
-                        ident_str.push_str(&ident.to_string());
-                        ident_str.push_str(suffix);
-                        Ident::new(&ident_str, ident.span())
-                    }
-                };
+                        match generate_new_type_ident(ident, struct_config, field_config) {
+                            NewTypeName::Renamed(new_ident) => {
+                                renamed = true;
+                                new_ident
+                            }
+                            // keep_types中的类型保持不变, 不需要转换约束
+                            NewTypeName::Kept => return Ok(ty.clone()),
+                            NewTypeName::Mirror => {
+                                mirror = true;
+                                ident.clone()
+                            }
+                        }
+                    };
                 last.ident = new_ident;
             }
+            // 镜像类型的泛型参数同样转换, 例如models::Page<models::Address>转换为models::PageVo<models::AddressVo>;
+            // type_name直接指定了字段的类型名, 保留原有的泛型参数; patch结构体中的泛型参数同样保持不变
+            if !mirror && field_config.type_name.is_none() && struct_config.patch.is_none() {
+                transform_generic_args(
+                    &mut new_path,
+                    struct_config,
+                    field_config,
+                    from_impls,
+                    into_impls,
+                )?;
+            }
+            // 配置了mirror_module时, 用其替换路径中最后一段之前的模块路径,
+            // 否则保留原路径前缀, 镜像类型与原始类型位于同一模块
+            if renamed && let Some(mirror_module) = &struct_config.mirror_module {
+                let last = new_path.segments.pop().map(|pair| pair.into_value());
+                new_path = mirror_module.clone();
+                new_path.segments.extend(last);
+            }
 
             // 克隆类型以延长其生命周期
             let orig_ty = ty.clone();
-            let new_ty = Type::Path(TypePath {
-                qself: None,
-                path: new_path,
-            });
+            let new_ty = if mirror {
+                let mirror_id =
+                    Literal::u64_suffixed(snake_to_camel_runtime::mirror_id(&struct_config.id));
+                let runtime_crate = struct_config.runtime_crate();
+                syn::parse_quote! { <#orig_ty as #runtime_crate::CamelMirror<#mirror_id>>::Mirror }
+            } else {
+                Type::Path(TypePath {
+                    qself: None,
+                    path: new_path,
+                })
+            };
 
             // 解决临时值生命周期问题：使用单独的作用域和变量存储
             let from_predicate = {
@@ -595,8 +1341,88 @@ fn transform_type<'a>(
             format!("unsupport type: {:?}", ty),
             // format!("不支持的类型: {:?}", ty),
         )),
+    }
+}
+
+/// 递归转换路径中每一段的泛型类型参数
+fn transform_generic_args(
+    path: &mut syn::Path,
+    struct_config: &StructConfig,
+    field_config: &GenFieldConfig,
+    from_impls: &mut Vec<WherePredicate>,
+    into_impls: &mut Vec<WherePredicate>,
+) -> syn::Result<()> {
+    for segment in &mut path.segments {
+        if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+            for arg in &mut args.args {
+                if let syn::GenericArgument::Type(ty) = arg {
+                    *ty = transform_type(ty, struct_config, field_config, from_impls, into_impls)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 生成跳过字段的默认值赋值
+fn default_field_value(
+    ident: &Ident,
+    ty: &Type,
+    default: Option<&Expr>,
+    where_clause: &mut syn::WhereClause,
+) -> proc_macro2::TokenStream {
+    let value = default_value(ty, default, where_clause);
+    quote! {
+        #ident: #value
+    }
+}
+
+/// 生成默认值表达式, 未配置default表达式时使用Default并添加约束
+fn default_value(
+    ty: &Type,
+    default: Option<&Expr>,
+    where_clause: &mut syn::WhereClause,
+) -> proc_macro2::TokenStream {
+    if let Some(default) = default {
+        return quote! { #default };
+    }
+    // 添加跳过字段的Default约束（去重）
+    let predicate: WherePredicate = syn::parse_quote! { #ty: Default };
+    if !where_clause.predicates.iter().any(|p| p == &predicate) {
+        where_clause.predicates.push(predicate);
+    }
+    quote! {
+        <#ty as Default>::default()
+    }
+}
+
+/// 判断字段是否为嵌套的patch类型: 自定义类型且转换后的类型名发生了变化
+/// 新类型是否为嵌套类型按当前patch id的prefix/suffix生成的patch结构体,
+/// type_name等指向其它类型时使用into转换
+fn is_nested_patch_type(original_ty: &Type, new_ty: &Type, struct_config: &StructConfig) -> bool {
+    let (Type::Path(original_path), Type::Path(new_path)) = (original_ty, new_ty) else {
+        return false;
+    };
+    if original_path.qself.is_some()
+        || new_path.qself.is_some()
+        || is_basic_type(original_ty)
+        || is_std_collection_type(original_ty)
+        || is_smart_pointer_type(original_ty)
+    {
+        return false;
+    }
+    let (Some(original), Some(new)) = (
+        original_path.path.segments.last(),
+        new_path.path.segments.last(),
+    ) else {
+        return false;
+    };
+    let patch_config = StructConfig {
+        name: None,
+        ..struct_config.clone()
     };
-    result
+    generate_new_struct_ident(&original.ident, &patch_config)
+        .is_ok_and(|patch_ident| patch_ident == new.ident)
 }
 
 fn is_basic_type(ty: &Type) -> bool {
@@ -665,38 +1491,52 @@ fn is_smart_pointer_type(ty: &Type) -> bool {
     }
 }
 
-fn add_where_clauses(
-    generics: &mut Generics,
-    from_impls: &[WherePredicate],
-    into_impls: &[WherePredicate],
-) {
-    let where_clause = generics
-        .where_clause
-        .get_or_insert_with(|| syn::WhereClause {
-            where_token: syn::Token![where](Span::call_site()),
-            predicates: Punctuated::new(),
-        });
-
-    // 添加From约束（去重）
+fn add_where_clauses(where_clause: &mut syn::WhereClause, predicates: &[WherePredicate]) {
+    // 添加约束（去重）
     let mut existing_preds = HashSet::default();
     for p in &where_clause.predicates {
         existing_preds.insert(quote!(#p).to_string());
     }
 
-    for pred in from_impls {
+    for pred in predicates {
         let pred_str = quote!(#pred).to_string();
         if !existing_preds.contains(&pred_str) {
             where_clause.predicates.push(pred.clone());
             existing_preds.insert(pred_str);
         }
     }
+}
 
-    // 添加Into约束（去重）
-    for pred in into_impls {
-        let pred_str = quote!(#pred).to_string();
-        if !existing_preds.contains(&pred_str) {
-            where_clause.predicates.push(pred.clone());
-            existing_preds.insert(pred_str);
-        }
+#[cfg(test)]
+mod tests {
+    use super::rewrite_doc_links;
+
+    fn renames() -> Vec<(String, String)> {
+        vec![
+            ("user_name".to_string(), "userName".to_string()),
+            ("id".to_string(), "id".to_string()),
+        ]
+    }
+
+    #[test]
+    fn test_rewrite_intra_doc_links() {
+        assert_eq!(
+            rewrite_doc_links(" 参见 [user_name] 和 [`user_name`]", &renames()),
+            " 参见 [userName] 和 [`userName`]"
+        );
+        assert_eq!(
+            rewrite_doc_links(
+                " 与 Self::user_name 相同, 见 [`Self::user_name`]",
+                &renames()
+            ),
+            " 与 Self::userName 相同, 见 [`Self::userName`]"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_doc_links_unchanged() {
+        // 普通文本, 反引号中的字段名, 其它链接和更长的字段名都保持不变
+        let doc = " user_name 即 `user_name`, 见 [user_name_v2], [crate::user_name], Self::user_name_v2 和 [id]";
+        assert_eq!(rewrite_doc_links(doc, &renames()), doc);
     }
 }
@@ -0,0 +1,215 @@
+// 配置了map_generics的泛型结构体的From实现中, 镜像结构体使用单独的类型参数, 使Page<Address>可以转换为PageVo<AddressVo>
+use quote::format_ident;
+use syn::{
+    GenericArgument, GenericParam, Generics, Ident, PathArguments, Type, TypePath, WherePredicate,
+    visit_mut::{self, VisitMut},
+};
+
+/// 原始结构体的类型参数, 以及From实现中镜像结构体对应的类型参数(`T`对应`TMirror`)
+#[derive(Default)]
+pub(crate) struct MirrorParams {
+    params: Vec<(Ident, Ident)>,
+}
+
+impl MirrorParams {
+    /// 只有直接作为字段类型或Vec, LinkedList元素的类型参数可以逐个转换, 使用单独的镜像类型参数;
+    /// 出现在Option, 元组或其它泛型类型中的类型参数与原始结构体相同
+    pub(crate) fn new<'a>(
+        generics: &Generics,
+        field_types: impl IntoIterator<Item = &'a Type>,
+    ) -> Self {
+        let mut params = generics
+            .type_params()
+            .map(|param| (param.ident.clone(), format_ident!("{}Mirror", param.ident)))
+            .collect::<Vec<_>>();
+        for ty in field_types {
+            if is_convertible(ty) {
+                continue;
+            }
+            let mut ty = ty.clone();
+            let mut finder = ParamFinder {
+                params: &params,
+                found: Vec::new(),
+            };
+            finder.visit_type_mut(&mut ty);
+            let found = finder.found;
+            params.retain(|(original, _)| !found.contains(original));
+        }
+        MirrorParams { params }
+    }
+
+    /// From实现中镜像结构体一侧的类型, 原始类型参数替换为镜像类型参数
+    pub(crate) fn map_type(&self, ty: &Type) -> Type {
+        let mut ty = ty.clone();
+        self.replacer(false).visit_type_mut(&mut ty);
+        ty
+    }
+
+    /// `原始类型: Into<镜像类型>`形式的约束, 只替换Into中的镜像类型
+    pub(crate) fn map_from_predicates(&self, predicates: &mut [WherePredicate]) {
+        for predicate in predicates {
+            if let WherePredicate::Type(predicate) = predicate {
+                for bound in &mut predicate.bounds {
+                    self.replacer(false).visit_type_param_bound_mut(bound);
+                }
+            }
+        }
+    }
+
+    /// `镜像类型: Into<原始类型>`形式的约束, 只替换被约束的镜像类型
+    pub(crate) fn map_into_predicates(&self, predicates: &mut [WherePredicate]) {
+        for predicate in predicates {
+            if let WherePredicate::Type(predicate) = predicate {
+                self.replacer(false)
+                    .visit_type_mut(&mut predicate.bounded_ty);
+            }
+        }
+    }
+
+    /// 将镜像类型参数替换回原始类型参数, 用于镜像结构体与原始结构体类型参数相同的实现
+    pub(crate) fn unmap_predicates(&self, predicates: &mut [WherePredicate]) {
+        for predicate in predicates {
+            self.replacer(true).visit_where_predicate_mut(predicate);
+        }
+    }
+
+    /// From实现的泛型参数: 原始结构体的泛型参数加上镜像类型参数, 镜像类型参数带有同样的约束
+    pub(crate) fn impl_generics(&self, generics: &Generics) -> Generics {
+        let mut impl_generics = generics.clone();
+        for param in generics.type_params() {
+            let Some(mirror) = self.mirror_ident(&param.ident) else {
+                continue;
+            };
+            let mut param = param.clone();
+            param.ident = mirror.clone();
+            param.attrs.clear();
+            param.default = None;
+            param.eq_token = None;
+            for bound in &mut param.bounds {
+                self.replacer(false).visit_type_param_bound_mut(bound);
+            }
+            impl_generics.params.push(GenericParam::Type(param));
+        }
+        impl_generics
+    }
+
+    /// 原始结构体where子句中涉及类型参数的约束, 替换为镜像类型参数后同样作用于镜像结构体
+    pub(crate) fn where_predicates(&self, generics: &Generics) -> Vec<WherePredicate> {
+        let Some(where_clause) = &generics.where_clause else {
+            return Vec::new();
+        };
+        where_clause
+            .predicates
+            .iter()
+            .map(|predicate| {
+                let mut predicate = predicate.clone();
+                self.replacer(false)
+                    .visit_where_predicate_mut(&mut predicate);
+                predicate
+            })
+            .collect()
+    }
+
+    /// 镜像结构体在From实现中的泛型参数, 类型参数替换为镜像类型参数
+    pub(crate) fn mirror_generics(&self, generics: &Generics) -> Generics {
+        let mut mirror_generics = generics.clone();
+        for param in &mut mirror_generics.params {
+            if let GenericParam::Type(param) = param
+                && let Some(mirror) = self.mirror_ident(&param.ident)
+            {
+                param.ident = mirror.clone();
+            }
+        }
+        mirror_generics
+    }
+
+    fn mirror_ident(&self, ident: &Ident) -> Option<&Ident> {
+        self.params
+            .iter()
+            .find(|(original, _)| original == ident)
+            .map(|(_, mirror)| mirror)
+    }
+
+    fn replacer(&self, reverse: bool) -> Replacer<'_> {
+        Replacer {
+            params: self,
+            reverse,
+        }
+    }
+}
+
+/// 替换类型路径开头的类型参数, 例如`T`和`T::Item`
+struct Replacer<'a> {
+    params: &'a MirrorParams,
+    reverse: bool,
+}
+
+impl VisitMut for Replacer<'_> {
+    fn visit_type_path_mut(&mut self, type_path: &mut TypePath) {
+        if type_path.qself.is_none()
+            && type_path.path.leading_colon.is_none()
+            && let Some(first) = type_path.path.segments.first_mut()
+        {
+            let replacement = self.params.params.iter().find_map(|(original, mirror)| {
+                let (from, to) = if self.reverse {
+                    (mirror, original)
+                } else {
+                    (original, mirror)
+                };
+                (&first.ident == from).then(|| to.clone())
+            });
+            if let Some(replacement) = replacement {
+                first.ident = replacement;
+            }
+        }
+        visit_mut::visit_type_path_mut(self, type_path);
+    }
+}
+
+/// 字段类型是否为`T`, `Vec<T>`或`LinkedList<T>`这样不带泛型参数的单段路径或其集合
+fn is_convertible(ty: &Type) -> bool {
+    let is_plain = |ty: &Type| {
+        matches!(ty, Type::Path(type_path)
+            if type_path.qself.is_none()
+                && type_path.path.segments.len() == 1
+                && type_path.path.segments[0].arguments.is_none())
+    };
+    if is_plain(ty) {
+        return true;
+    }
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return false;
+    };
+    type_path.qself.is_none()
+        && matches!(segment.ident.to_string().as_str(), "Vec" | "LinkedList")
+        && args.args.len() == 1
+        && matches!(args.args.first(), Some(GenericArgument::Type(inner)) if is_plain(inner))
+}
+
+/// 查找类型中出现的类型参数
+struct ParamFinder<'a> {
+    params: &'a [(Ident, Ident)],
+    found: Vec<Ident>,
+}
+
+impl VisitMut for ParamFinder<'_> {
+    fn visit_type_path_mut(&mut self, type_path: &mut TypePath) {
+        if type_path.qself.is_none()
+            && type_path.path.leading_colon.is_none()
+            && let Some(first) = type_path.path.segments.first()
+            && self
+                .params
+                .iter()
+                .any(|(original, _)| original == &first.ident)
+        {
+            self.found.push(first.ident.clone());
+        }
+        visit_mut::visit_type_path_mut(self, type_path);
+    }
+}
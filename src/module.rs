@@ -0,0 +1,202 @@
+// gen_camel的module配置: 将生成的代码放入子模块, 以及合并多个结构体同名子模块的gen_camel_module属性宏
+use crate::{GeneratedParts, expand_derive_generate_struct};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Attribute, Ident, Item, ItemImpl, ItemMod, Path, PathSegment, Token, Variant, Visibility,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    visit_mut::{self, VisitMut},
+};
+
+/// gen_camel_module添加到已展开结构体上的标记, 派生宏据此跳过配置了module的id
+pub(crate) const EXPANDED_MARKER: &str = "gen_camel_expanded";
+
+/// 子模块名以及放入其中的代码, 按首次出现的顺序排列
+type Modules = Vec<(Ident, Vec<TokenStream>)>;
+
+/// 派生宏的输出: 未配置module的代码保持原位, 其余代码按module放入`pub mod`,
+/// 结构体带有gen_camel_module的标记时配置了module的代码已经由gen_camel_module生成
+pub(crate) fn wrap_modules(parts: GeneratedParts, expanded: bool) -> syn::Result<TokenStream> {
+    let mut outside = Vec::new();
+    let mut modules = Modules::new();
+    group_parts(parts, &mut outside, &mut modules);
+    if expanded {
+        modules.clear();
+    }
+    let modules = modules
+        .into_iter()
+        .map(|(name, code)| module_tokens(&name, code))
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        #(#outside)*
+        #(#modules)*
+    })
+}
+
+fn group_parts(parts: GeneratedParts, outside: &mut Vec<TokenStream>, modules: &mut Modules) {
+    for (module, code) in parts {
+        match module {
+            None => outside.push(code),
+            Some(module) => match modules.iter_mut().find(|(name, _)| *name == module) {
+                Some((_, codes)) => codes.push(code),
+                None => modules.push((module, vec![code])),
+            },
+        }
+    }
+}
+
+fn module_tokens(name: &Ident, code: Vec<TokenStream>) -> syn::Result<TokenStream> {
+    let mut file: syn::File = syn::parse2(quote! { #(#code)* })?;
+    Relocate::default().visit_file_mut(&mut file);
+    let items = file.items;
+    Ok(quote! {
+        #[doc = "Camel-case mirrors generated by `snake_to_camel`."]
+        pub mod #name {
+            #[allow(unused_imports)]
+            use super::*;
+            #(#items)*
+        }
+    })
+}
+
+/// 调整移动到子模块中的代码, 使其与在原模块中的含义相同
+///
+/// 以`self::`或`super::`开头的相对路径多一层`super`, 原模块中的其它名称通过`use super::*`引入;
+/// 私有和`pub(super)`等相对可见性扩大一层, 使原模块仍然可以访问生成的结构体和字段。
+#[derive(Default)]
+struct Relocate {
+    // trait实现中的项和枚举变体中的字段不能有可见性
+    keep_vis: bool,
+}
+
+impl VisitMut for Relocate {
+    fn visit_path_mut(&mut self, path: &mut Path) {
+        if path.segments.len() > 1 {
+            relocate_path(path);
+        }
+        visit_mut::visit_path_mut(self, path);
+    }
+
+    fn visit_visibility_mut(&mut self, vis: &mut Visibility) {
+        if self.keep_vis {
+            return;
+        }
+        match vis {
+            Visibility::Public(_) => {}
+            Visibility::Inherited => *vis = syn::parse_quote!(pub(super)),
+            Visibility::Restricted(restricted) => {
+                let mut path = (*restricted.path).clone();
+                if relocate_path(&mut path) {
+                    *vis = syn::parse_quote!(pub(in #path));
+                }
+            }
+        }
+    }
+
+    fn visit_item_impl_mut(&mut self, item: &mut ItemImpl) {
+        let keep_vis = std::mem::replace(&mut self.keep_vis, item.trait_.is_some());
+        visit_mut::visit_item_impl_mut(self, item);
+        self.keep_vis = keep_vis;
+    }
+
+    fn visit_variant_mut(&mut self, variant: &mut Variant) {
+        let keep_vis = std::mem::replace(&mut self.keep_vis, true);
+        visit_mut::visit_variant_mut(self, variant);
+        self.keep_vis = keep_vis;
+    }
+
+    fn visit_item_const_mut(&mut self, item: &mut syn::ItemConst) {
+        // `const _`只用于引用配置文件, 不需要可见性
+        let keep_vis = std::mem::replace(&mut self.keep_vis, item.ident == "_");
+        visit_mut::visit_item_const_mut(self, item);
+        self.keep_vis = keep_vis;
+    }
+}
+
+/// `self::x` -> `super::x`, `super::x` -> `super::super::x`, 返回是否修改了路径
+fn relocate_path(path: &mut Path) -> bool {
+    if path.leading_colon.is_some() {
+        return false;
+    }
+    let Some(first) = path.segments.first_mut() else {
+        return false;
+    };
+    if first.ident == "self" {
+        first.ident = Ident::new("super", first.ident.span());
+        true
+    } else if first.ident == "super" {
+        let segment = PathSegment::from(Ident::new("super", first.ident.span()));
+        path.segments.insert(0, segment);
+        true
+    } else {
+        false
+    }
+}
+
+/// 展开gen_camel_module: 模块中配置了module的结构体由这里展开,
+/// 同名的module合并为一个`pub mod`放在模块末尾
+pub(crate) fn expand_gen_camel_module(
+    attr: TokenStream,
+    mut item_mod: ItemMod,
+) -> syn::Result<TokenStream> {
+    if !attr.is_empty() {
+        return Err(syn::Error::new(
+            attr.span(),
+            "gen_camel_module does not take arguments",
+            // "gen_camel_module 不接受参数",
+        ));
+    }
+    let Some((_, items)) = &mut item_mod.content else {
+        return Err(syn::Error::new(
+            item_mod.span(),
+            "gen_camel_module can only be used on an inline module",
+            // "gen_camel_module 只能用在内联模块上",
+        ));
+    };
+    let mut modules = Modules::new();
+    for item in items.iter_mut() {
+        let Item::Struct(item_struct) = item else {
+            continue;
+        };
+        if !derives_gen_camel_case(&item_struct.attrs) {
+            continue;
+        }
+        // 与派生宏的输入一致, 不包含derive属性
+        let mut input: syn::DeriveInput = item_struct.clone().into();
+        input.attrs.retain(|attr| !attr.path().is_ident("derive"));
+        // 展开失败或没有配置module时交给派生宏处理, 由派生宏报告错误
+        if let Ok(parts) = expand_derive_generate_struct(input)
+            && parts.iter().any(|(module, _)| module.is_some())
+        {
+            // 未配置module的代码仍由派生宏生成
+            group_parts(parts, &mut Vec::new(), &mut modules);
+            let marker = Ident::new(EXPANDED_MARKER, proc_macro2::Span::call_site());
+            item_struct.attrs.push(syn::parse_quote!(#[#marker]));
+        }
+    }
+    for (name, code) in modules {
+        items.push(Item::Verbatim(module_tokens(&name, code)?));
+    }
+    Ok(quote! { #item_mod })
+}
+
+fn is_gen_camel_case(path: &Path) -> bool {
+    path.segments
+        .last()
+        .is_some_and(|segment| segment.ident == "GenCamelCase")
+}
+
+fn derive_paths(attr: &Attribute) -> Option<Punctuated<Path, Token![,]>> {
+    if !attr.path().is_ident("derive") {
+        return None;
+    }
+    attr.parse_args_with(Punctuated::parse_terminated).ok()
+}
+
+fn derives_gen_camel_case(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter_map(derive_paths)
+        .any(|paths| paths.iter().any(is_gen_camel_case))
+}
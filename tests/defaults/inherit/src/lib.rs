@@ -0,0 +1 @@
+//! 没有自己的默认配置, 使用上级目录tests/defaults中snake_to_camel.toml的测试crate, 测试位于tests目录
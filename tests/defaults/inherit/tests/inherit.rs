@@ -0,0 +1,31 @@
+use snake_to_camel::GenCamelCase;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Decimal(i64);
+
+// 使用上级目录中最近的snake_to_camel.toml, dictionary相对于该文件所在的目录
+#[derive(Debug, Clone, PartialEq, GenCamelCase)]
+struct Order {
+    order_id: u64,
+    order_qty: u32,
+    total: Decimal,
+}
+
+#[test]
+fn test_inherit_defaults() {
+    let order = Order {
+        order_id: 1,
+        order_qty: 2,
+        total: Decimal(300),
+    };
+    let dto = OrderDto::from(order.clone());
+    assert_eq!(
+        dto.clone(),
+        OrderDto {
+            orderID: 1,
+            orderQuantity: 2,
+            total: Decimal(300),
+        }
+    );
+    assert_eq!(Order::from(dto), order);
+}
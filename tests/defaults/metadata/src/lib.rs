@@ -0,0 +1 @@
+//! Cargo.toml中[package.metadata.snake_to_camel]默认配置的测试crate, 测试位于tests目录
@@ -0,0 +1,29 @@
+use snake_to_camel::GenCamelCase;
+
+// 使用[package.metadata.snake_to_camel]中的默认配置, 不使用上级目录的snake_to_camel.toml
+#[derive(Debug, Clone, PartialEq, GenCamelCase)]
+struct Item {
+    item_sku: String,
+    line2: String,
+    stock_qty: u32,
+}
+
+#[test]
+fn test_metadata_defaults() {
+    let item = Item {
+        item_sku: "A-1".to_string(),
+        line2: "B".to_string(),
+        stock_qty: 3,
+    };
+    let vo = ItemVo::from(item.clone());
+    // 上级目录中dictionary的qty没有生效
+    assert_eq!(
+        vo.clone(),
+        ItemVo {
+            itemSKU: "A-1".to_string(),
+            line2: "B".to_string(),
+            stockQty: 3,
+        }
+    );
+    assert_eq!(Item::from(vo), item);
+}
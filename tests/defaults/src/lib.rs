@@ -0,0 +1 @@
+//! snake_to_camel.toml默认配置和Cargo.toml中预设的测试crate, 测试位于tests目录
@@ -0,0 +1,71 @@
+use snake_to_camel::GenCamelCase;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Decimal(i64);
+
+// 1. 全部使用默认配置
+#[derive(Debug, Clone, PartialEq, GenCamelCase)]
+struct Product {
+    product_id: u64,
+    image_url: String,
+    stock_qty: u32,
+    price: Decimal,
+}
+
+// 2. 显式配置覆盖默认配置, derive与默认配置合并
+#[derive(Debug, Clone, PartialEq, Default, GenCamelCase)]
+#[gen_camel(suffix = "View", derive = "Default", acronyms = "ID")]
+struct Shop {
+    shop_id: u64,
+    home_url: String,
+}
+
+// 3. 显式配置的prefix与默认的suffix不会组合, 结构体名为EvtSignal而不是EvtSignalDto
+#[derive(Debug, Clone, PartialEq, Default, GenCamelCase)]
+#[gen_camel(prefix = "Evt")]
+struct Signal {
+    signal_id: u64,
+}
+
+#[test]
+fn test_defaults() {
+    let product = Product {
+        product_id: 1,
+        image_url: "https://example.com/1.png".to_string(),
+        stock_qty: 3,
+        price: Decimal(100),
+    };
+    let dto = ProductDto::from(product.clone());
+    // derive(Debug, Clone, PartialEq)来自默认配置
+    assert_eq!(
+        dto.clone(),
+        ProductDto {
+            productID: 1,
+            imageURL: "https://example.com/1.png".to_string(),
+            stockQuantity: 3,
+            price: Decimal(100),
+        }
+    );
+    assert_eq!(Product::from(dto), product);
+}
+
+#[test]
+fn test_override_defaults() {
+    let view = ShopView::default();
+    assert_eq!(
+        view,
+        ShopView {
+            shopID: 0,
+            homeUrl: String::new(),
+        }
+    );
+    assert_eq!(Shop::from(view), Shop::default());
+}
+
+#[test]
+fn test_explicit_affix() {
+    let signal = Signal { signal_id: 5 };
+    let evt = EvtSignal::from(signal.clone());
+    assert_eq!(evt, EvtSignal { signalID: 5 });
+    assert_eq!(Signal::from(evt), signal);
+}
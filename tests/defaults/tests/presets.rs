@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use snake_to_camel::GenCamelCase;
+
+// 1. 预设测试, 预设定义在Cargo.toml的[package.metadata.snake_to_camel.presets]中,
+// 预设中没有的配置使用snake_to_camel.toml中的默认配置
+#[derive(Debug, Clone, PartialEq, GenCamelCase)]
+#[gen_camel(preset = "api")]
+#[gen_camel(id = "event", preset = "event")]
+#[gen_camel(id = "row", preset = "row", derive = "Debug, Default")]
+struct Account {
+    account_id: u64,
+    avatar_url: String,
+    oauth2token: String,
+}
+
+// 生成的结构体
+// #[derive(Debug, Clone, PartialEq, Serialize)]
+// struct AccountResponse {
+//     accountID: u64,
+//     avatarURL: String,
+//     oauth2token: String,
+// }
+// #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+// struct AccountEvent {
+//     accountID: u64,
+//     avatarURL: String,
+//     oauth2Token: String,
+// }
+// #[derive(Debug, Clone, PartialEq, Default)]
+// struct DbAccountRow { ... }
+
+#[test]
+fn test_presets() {
+    let account = Account {
+        account_id: 7,
+        avatar_url: "https://example.com/7.png".to_string(),
+        oauth2token: "abc".to_string(),
+    };
+    // api: direction = "to", acronyms = "ID, URL"
+    let response = AccountResponse::from(account.clone());
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        serde_json::json!({
+            "accountID": 7,
+            "avatarURL": "https://example.com/7.png",
+            "oauth2token": "abc",
+        })
+    );
+    // event: digits = "new_word", acronyms来自默认配置
+    let event = AccountEvent::from(account.clone());
+    let json = serde_json::to_string(&event).unwrap();
+    assert!(json.contains("\"oauth2Token\""));
+    assert!(json.contains("\"accountID\""));
+    let event: AccountEvent = serde_json::from_str(&json).unwrap();
+    assert_eq!(Account::from(event), account);
+    // row: prefix = "Db", builder, 显式配置的derive与预设合并
+    let row = DbAccountRow::builder()
+        .accountID(7u64)
+        .avatarURL("https://example.com/7.png".to_string())
+        .oauth2token("abc".to_string())
+        .build()
+        .unwrap();
+    assert_eq!(Account::from(row), account);
+    assert_eq!(DbAccountRow::default().accountID, 0);
+}
@@ -1,6 +1,10 @@
+use serde::{Deserialize, Serialize};
 use snake_to_camel::GenCamelCase;
 use std::collections::HashMap;
 
+#[path = "../snake_to_camel_runtime/tests/fixtures/mod.rs"]
+mod fixtures;
+
 // 1. 基本转换测试 - 验证snake_case到camelCase的转换
 #[derive(Debug, PartialEq, GenCamelCase)]
 #[gen_camel(name = "BasicUserDto")]
@@ -123,7 +127,605 @@ struct RecursiveParent {
 //     pub testName: DateTime<Utc>,
 // }
 
+// 8. 单向跳过字段测试
+#[derive(Debug, PartialEq, GenCamelCase)]
+#[gen_camel(name = "DirectionalSkipDto")]
+struct DirectionalSkip {
+    user_name: String,
+    #[gen_field(skip_into)]
+    created_at: u64,
+    #[gen_field(skip_from, default = "\"unknown\".to_string()")]
+    client_tag: String,
+    #[gen_field(field_skip, default = "7")]
+    internal_level: u8,
+}
+// struct DirectionalSkipDto {
+//     userName: String,
+//     createdAt: u64,
+//     clientTag: String,
+// }
+
+// 9. 单向转换测试
+#[derive(Debug, PartialEq, GenCamelCase)]
+#[gen_camel(name = "OneWayDto", direction = "to")]
+#[gen_camel(id = "form", name = "OneWayForm", direction = "from")]
+struct OneWay<T> {
+    item_name: String,
+    #[gen_field(id = "form", skip_into)]
+    payload: T,
+}
+// 手写的反向转换, 不会和生成的实现冲突
+impl<T: Default> From<OneWayDto<T>> for OneWay<T> {
+    fn from(dto: OneWayDto<T>) -> Self {
+        OneWay {
+            item_name: dto.itemName,
+            payload: T::default(),
+        }
+    }
+}
+
+// 10. 可见性配置测试
+mod visibility {
+    use snake_to_camel::GenCamelCase;
+
+    #[derive(GenCamelCase)]
+    #[gen_camel(name = "AccountDto", vis = "pub")]
+    struct Account {
+        #[gen_field(vis = "pub")]
+        #[add_field(field_name = "version", field_type = "u32")]
+        #[add_field(field_name = "etag", field_type = "String", vis = "pub(crate)")]
+        account_id: u64,
+        #[gen_field(vis = "pub(crate)")]
+        display_name: String,
+    }
+    // pub struct AccountDto {
+    //     pub accountId: u64,
+    //     pub(crate) displayName: String,
+    //     pub version: u32,
+    //     pub(crate) etag: String,
+    // }
+
+    pub fn account_dto() -> AccountDto {
+        Account {
+            account_id: 7,
+            display_name: "private".to_string(),
+        }
+        .into()
+    }
+}
+
+// 11. 属性透传测试
+#[derive(Debug, PartialEq, GenCamelCase, Serialize)]
+#[serde(deny_unknown_fields)]
+#[gen_camel(name = "ForwardedDto", derive = "Serialize")]
+#[gen_camel(
+    id = "plain",
+    name = "PlainDto",
+    forward_attrs = false,
+    attr = "derive(Clone, Serialize)"
+)]
+#[gen_camel(
+    id = "docs",
+    name = "DocsDto",
+    forward_attrs = "doc",
+    derive = "Deserialize"
+)]
+struct AttrForward {
+    #[serde(rename = "ID")]
+    #[gen_field(id = "plain", attr = "serde(rename = \"uid\")")]
+    record_id: u64,
+    /// 原始字段文档
+    #[serde(skip)]
+    note: String,
+}
+// #[derive(Serialize)]
+// #[serde(deny_unknown_fields)]
+// struct ForwardedDto {
+//     #[serde(rename = "ID")]
+//     recordId: u64,
+//     #[serde(skip)]
+//     note: String,
+// }
+// #[derive(Clone, Serialize)]
+// struct PlainDto {
+//     #[serde(rename = "uid")]
+//     recordId: u64,
+//     note: String,
+// }
+
+// 12. 文档注释测试
+/// 用户资料, 参见 [`Self::user_name`]
+#[derive(Debug, PartialEq, GenCamelCase)]
+#[gen_camel(name = "ProfileDto", rewrite_doc_links)]
+struct Profile {
+    /// 用户名, 与 [`nick_name`] 不同
+    #[add_field(field_name = "avatarUrl", field_type = "String", doc = "头像地址")]
+    user_name: String,
+    /// 昵称
+    nick_name: String,
+}
+// /// Camel-case mirror of [`Profile`].
+// ///
+// /// 用户资料, 参见 [`Self::userName`]
+// struct ProfileDto {
+//     /// 用户名, 与 [`nickName`] 不同
+//     userName: String,
+//     /// 昵称
+//     nickName: String,
+//     /// 头像地址
+//     avatarUrl: String,
+// }
+
+// 13. patch结构体测试
+#[derive(Debug, PartialEq, Clone, GenCamelCase)]
+#[gen_camel(suffix = "Vo")]
+#[gen_camel(
+    id = "patch",
+    suffix = "Patch",
+    patch,
+    diff,
+    derive = "Debug, Default, Deserialize"
+)]
+struct Address {
+    street_name: String,
+    zip_code: u32,
+}
+
+#[derive(Debug, PartialEq, Clone, GenCamelCase)]
+#[gen_camel(name = "MemberDto")]
+#[gen_camel(
+    id = "patch",
+    suffix = "Patch",
+    patch,
+    diff,
+    derive = "Debug, Default, Deserialize"
+)]
+struct Member {
+    #[gen_field(id = "patch", field_skip)]
+    member_id: u64,
+    display_name: String,
+    tags: Vec<String>,
+    home_address: Address,
+}
+// #[derive(Default, Deserialize)]
+// struct MemberPatch {
+//     displayName: Option<String>,
+//     tags: Option<Vec<String>>,
+//     homeAddress: Option<AddressPatch>,
+// }
+
+// type_name指向手写的类型时不作为嵌套patch, 使用into转换
+#[derive(Debug, PartialEq, Clone)]
+struct Status {
+    code: u8,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct StatusDto {
+    code: u8,
+}
+
+impl From<Status> for StatusDto {
+    fn from(status: Status) -> Self {
+        StatusDto { code: status.code }
+    }
+}
+
+impl From<StatusDto> for Status {
+    fn from(dto: StatusDto) -> Self {
+        Status { code: dto.code }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, GenCamelCase)]
+#[gen_camel(
+    id = "patch",
+    suffix = "Patch",
+    patch,
+    diff,
+    derive = "Debug, Default, Deserialize"
+)]
+struct Ticket {
+    title: String,
+    #[gen_field(id = "patch", type_name = "StatusDto")]
+    status: Status,
+}
+
+// Option和Vec中的嵌套类型保持不变, 字段整体替换
+#[derive(Debug, PartialEq, Clone, GenCamelCase)]
+#[gen_camel(
+    id = "patch",
+    suffix = "Patch",
+    patch,
+    diff,
+    derive = "Debug, Default"
+)]
+struct Household {
+    primary_address: Option<Address>,
+    addresses: Vec<Address>,
+}
+// struct HouseholdPatch {
+//     primaryAddress: Option<Option<Address>>,
+//     addresses: Option<Vec<Address>>,
+// }
+
+// 14. builder测试
+#[derive(Debug, PartialEq, GenCamelCase)]
+#[gen_camel(name = "ShipmentVo", builder, derive = "Debug")]
+struct Shipment<T> {
+    #[add_field(field_name = "trackingUrl", field_type = "String")]
+    shipment_id: u64,
+    carrier_name: String,
+    #[gen_field(skip_from, default = "3")]
+    retry_count: u8,
+    payload: T,
+}
+
+// 15. 命名规则测试 - 字段名取自共享用例表
+#[derive(GenCamelCase)]
+#[gen_camel(name = "NamingVo")]
+struct Naming {
+    user_name: u8,
+    user_id: u8,
+    http_url: u8,
+    is_active: u8,
+    address_line_1: u8,
+    v2_token: u8,
+    sha256_hex: u8,
+    _private_flag: u8,
+    trailing_: u8,
+    single: u8,
+}
+
+// 16. 缩写词测试
+#[derive(GenCamelCase)]
+#[gen_camel(name = "AcronymVo", acronyms = "ID, URL, HTTP")]
+struct Acronym {
+    user_id: u8,
+    http_url: u8,
+    url: u8,
+    user_id_url: u8,
+    http_server_id: u8,
+    identity: u8,
+}
+
+// 生成的结构体
+// struct AcronymVo {
+//     userID: u8,
+//     httpURL: u8,
+//     url: u8,
+//     userIDURL: u8,
+//     httpServerID: u8,
+//     identity: u8,
+// }
+
+// 17. 数字规则测试
+#[derive(Debug, PartialEq, GenCamelCase)]
+#[gen_camel(digits = "new_word")]
+struct Oauth2token {
+    v2token: String,
+    address_line_1: u8,
+}
+
+#[derive(Debug, PartialEq, GenCamelCase)]
+#[gen_camel(digits = "new_word")]
+struct Credential {
+    sha256hex: String,
+    oauth2token: Oauth2token,
+}
+
+#[derive(Debug, PartialEq, GenCamelCase)]
+#[gen_camel(name = "PreservedVo", digits = "preserve")]
+struct Preserved {
+    address_line_1: u8,
+    v2_token: u8,
+}
+
+// 生成的结构体
+// struct Oauth2TokenVo {
+//     v2Token: String,
+//     addressLine1: u8,
+// }
+// struct CredentialVo {
+//     sha256Hex: String,
+//     oauth2Token: Oauth2TokenVo,
+// }
+// struct PreservedVo {
+//     addressLine_1: u8,
+//     v2_token: u8,
+// }
+
+// 18. 单词替换表测试
+#[derive(Debug, PartialEq, GenCamelCase)]
+#[gen_camel(dictionary = "tests/names.toml")]
+struct OrderLine {
+    order_qty: u32,
+    ship_addr_line_1: String,
+    home_tel: String,
+}
+
+// 生成的结构体
+// struct OrderLineVo {
+//     orderQuantity: u32,
+//     shipAddressLine1: String,
+//     homePhoneNumber: String,
+// }
+
+// 19. 保持类型不变测试, Money没有派生GenCamelCase
+#[derive(Debug, Clone, PartialEq)]
+struct Money(i64);
+
+#[derive(Debug, PartialEq, GenCamelCase)]
+#[gen_camel(keep_types = "Money, chrono::NaiveDate")]
+struct Invoice {
+    total_amount: Money,
+    line_amounts: Vec<Money>,
+    due_date: Option<chrono::NaiveDate>,
+}
+
+// 生成的结构体
+// struct InvoiceVo {
+//     totalAmount: Money,
+//     lineAmounts: Vec<Money>,
+//     dueDate: Option<chrono::NaiveDate>,
+// }
+
+// 20. 嵌套类型名测试, name只影响结构体本身, 嵌套类型名由type_prefix/type_suffix/type_map决定
+#[derive(Debug, Clone, PartialEq, GenCamelCase)]
+#[gen_camel(suffix = "Dto")]
+#[gen_camel(id = "view", name = "ItemView")]
+struct CartItem {
+    item_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, GenCamelCase)]
+#[gen_camel(suffix = "Dto")]
+#[gen_camel(id = "view", suffix = "View")]
+struct Coupon {
+    coupon_code: String,
+}
+
+#[derive(Debug, Clone, PartialEq, GenCamelCase)]
+#[gen_camel(name = "CartDto", type_suffix = "Dto")]
+#[gen_camel(id = "view", name = "CartView", type_suffix = "View", type_map = "CartItem = ItemView")]
+struct Cart {
+    cart_items: Vec<CartItem>,
+    coupons: Vec<Coupon>,
+    // 字段配置优先于结构体配置
+    #[gen_field(id = "view", type_suffix = "Dto")]
+    gift_coupon: Coupon,
+}
+
+// 生成的结构体
+// struct CartDto {
+//     cartItems: Vec<CartItemDto>,
+//     coupons: Vec<CouponDto>,
+//     giftCoupon: CouponDto,
+// }
+// struct CartView {
+//     cartItems: Vec<ItemView>,
+//     coupons: Vec<CouponView>,
+//     giftCoupon: CouponDto,
+// }
+
+// 21. 模块路径和限定路径类型测试
+mod models {
+    use snake_to_camel::GenCamelCase;
+
+    #[derive(Debug, Clone, PartialEq, GenCamelCase)]
+    #[gen_camel(derive = "Debug, Clone, PartialEq")]
+    pub struct Address {
+        pub street_name: String,
+    }
+
+    // map_generics: PageVo<AddressVo>可以由Page<Address>转换
+    #[derive(Debug, Clone, PartialEq, GenCamelCase)]
+    #[gen_camel(derive = "Debug, Clone, PartialEq", map_generics)]
+    pub struct Page<T> {
+        pub page_items: Vec<T>,
+    }
+}
+
+// 手写的镜像类型所在的模块, 与models中生成的AddressVo不是同一个类型
+mod dto {
+    use super::models::Address;
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AddressVo {
+        pub street: String,
+    }
+
+    impl From<Address> for AddressVo {
+        fn from(address: Address) -> Self {
+            AddressVo {
+                street: address.street_name,
+            }
+        }
+    }
+
+    impl From<AddressVo> for Address {
+        fn from(address: AddressVo) -> Self {
+            Address {
+                street_name: address.street,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PageVo<T> {
+        pub items: Vec<T>,
+    }
+
+    impl<T, U: From<T>> From<super::models::Page<T>> for PageVo<U> {
+        fn from(page: super::models::Page<T>) -> Self {
+            PageVo {
+                items: page.page_items.into_iter().map(U::from).collect(),
+            }
+        }
+    }
+
+    impl<T: From<U>, U> From<PageVo<U>> for super::models::Page<T> {
+        fn from(page: PageVo<U>) -> Self {
+            super::models::Page {
+                page_items: page.items.into_iter().map(T::from).collect(),
+            }
+        }
+    }
+}
+
+trait Keyed {
+    type Key;
+}
+
+impl Keyed for models::Address {
+    type Key = u64;
+}
+
+#[derive(Debug, Clone, PartialEq, GenCamelCase)]
+struct Customer {
+    home_address: crate::models::Address,
+    address_key: <models::Address as Keyed>::Key,
+}
+
+#[derive(Debug, Clone, PartialEq, GenCamelCase)]
+#[gen_camel(mirror_module = "crate::dto")]
+struct Supplier {
+    office_addresses: Vec<models::Address>,
+}
+
+// 镜像类型的泛型参数同样转换
+#[derive(Debug, Clone, PartialEq, GenCamelCase)]
+struct Branch {
+    address_page: models::Page<models::Address>,
+}
+
+#[derive(Debug, Clone, PartialEq, GenCamelCase)]
+#[gen_camel(mirror_module = "crate::dto")]
+struct Warehouse {
+    address_page: models::Page<models::Address>,
+}
+
+// 生成的结构体
+// struct CustomerVo {
+//     homeAddress: crate::models::AddressVo,
+//     addressKey: <models::Address as Keyed>::Key,
+// }
+// struct SupplierVo {
+//     officeAddresses: Vec<crate::dto::AddressVo>,
+// }
+// struct BranchVo {
+//     addressPage: models::PageVo<models::AddressVo>,
+// }
+// struct WarehouseVo {
+//     addressPage: crate::dto::PageVo<crate::dto::AddressVo>,
+// }
+
+// 22. 生成到子模块测试, 同一模块中的多个结构体通过gen_camel_module合并到同一个dto模块
+#[snake_to_camel::gen_camel_module]
+mod catalog {
+    use snake_to_camel::GenCamelCase;
+
+    #[derive(Debug, Clone, PartialEq, GenCamelCase)]
+    #[gen_camel(module = "dto", derive = "Debug, Clone, PartialEq")]
+    #[gen_camel(id = "flat", name = "CategoryRow")]
+    pub struct Category {
+        pub category_name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, GenCamelCase)]
+    #[gen_camel(module = "dto", derive = "Debug, Clone, PartialEq", keep_types = "Money")]
+    pub struct Product {
+        pub categories: Vec<Category>,
+        // dto中为super::super::Money
+        pub unit_price: super::Money,
+    }
+}
+
+// 生成的代码
+// mod catalog {
+//     pub struct Category { ... }
+//     pub struct CategoryRow { ... }
+//     pub struct Product { ... }
+//     pub mod dto {
+//         use super::*;
+//         pub struct CategoryVo { pub categoryName: String }
+//         pub struct ProductVo { pub categories: Vec<CategoryVo>, pub unitPrice: super::super::Money }
+//     }
+// }
+
+// 只有一个结构体时可以不使用gen_camel_module, 私有结构体和字段在dto中为pub(super)
+mod shipping {
+    use snake_to_camel::GenCamelCase;
+
+    #[derive(Debug, Clone, PartialEq, GenCamelCase)]
+    #[gen_camel(module = "dto")]
+    struct Parcel {
+        parcel_id: u64,
+    }
+
+    pub fn parcel_id(parcel_id: u64) -> u64 {
+        let vo = dto::ParcelVo::from(Parcel { parcel_id });
+        let vo_parcel_id = vo.parcelId;
+        Parcel::from(vo).parcel_id + vo_parcel_id
+    }
+}
+
+// 23. 原地改写测试, camel_struct不生成新的结构体, 只将字段名改为camelCase
+/// 用户, 主键为 [`user_id`]
+#[snake_to_camel::camel_struct(acronyms = "ID", rewrite_doc_links, builder)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Subscriber {
+    /// 参见 [`user_id`]
+    pub user_id: u64,
+    pub display_name: String,
+}
+
+// 生成的结构体
+// struct Subscriber {
+//     pub userID: u64,
+//     pub displayName: String,
+// }
+
+// 24. 外部结构体测试, camel_mirror通过声明的字段列表生成镜像结构体
+snake_to_camel::camel_mirror!(
+    #[gen_camel(derive = "Debug, Clone, PartialEq")]
+    pub std::ops::Range<T> {
+        pub start: T,
+        pub end: T,
+    } => RangeVo
+);
+
+mod vendor {
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServerConfig {
+        pub listen_port: u16,
+        pub max_connections: usize,
+        pub debug_mode: bool,
+    }
+}
+
+snake_to_camel::camel_mirror!(
+    #[gen_camel(prefix = "Api", suffix = "", derive = "Debug")]
+    pub vendor::ServerConfig {
+        pub listen_port: u16,
+        pub max_connections: usize,
+        #[gen_field(field_skip, default = "true")]
+        pub debug_mode: bool,
+    }
+);
+
+// 生成的结构体
+// struct RangeVo<T> {
+//     pub start: T,
+//     pub end: T,
+// }
+// struct ApiServerConfig {
+//     pub listenPort: u16,
+//     pub maxConnections: usize,
+// }
+
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_basic_struct_conversion() {
     let original = BasicUser {
         user_id: 1,
@@ -181,6 +783,7 @@ fn test_field_level_configurations() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_extra_fields_addition() {
     let original = BaseStruct {
         field_one: "value1".to_string(),
@@ -209,6 +812,17 @@ fn test_generic_struct_conversion() {
     assert_eq!(converted.dataId, 1);
     assert_eq!(converted.payload, "test payload");
     assert_eq!(converted.metadata, Some(42));
+
+    // 未配置map_generics时镜像结构体与原始结构体的类型参数相同, 不需要标注类型
+    let original = GenericData {
+        data_id: 2,
+        payload: 7u8,
+        metadata: Some("meta"),
+    };
+    let converted = GenericDataDto::from(original);
+    assert_eq!(converted.dataId, 2);
+    let reverted = GenericData::from(converted);
+    assert_eq!(reverted.payload, 7);
 }
 
 #[test]
@@ -250,4 +864,543 @@ fn test_recursive_type_conversion() {
     assert_eq!(converted.child.child_id, 1);
     assert_eq!(converted.grandchildren.len(), 1);
     assert_eq!(converted.grandchildren[0].value, "child");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_directional_skip_conversion() {
+    let original = DirectionalSkip {
+        user_name: "Test User".to_string(),
+        created_at: 1700000000,
+        client_tag: "web".to_string(),
+        internal_level: 3,
+    };
+
+    let converted: DirectionalSkipDto = original.into();
+    assert_eq!(converted.userName, "Test User");
+    // skip_into字段在from方向正常转换
+    assert_eq!(converted.createdAt, 1700000000);
+    // skip_from字段在from方向使用default表达式
+    assert_eq!(converted.clientTag, "unknown");
+
+    let reverted: DirectionalSkip = DirectionalSkipDto {
+        userName: "Test User".to_string(),
+        createdAt: 42,
+        clientTag: "app".to_string(),
+    }
+    .into();
+    // skip_into字段在into方向使用Default
+    assert_eq!(reverted.created_at, 0);
+    assert_eq!(reverted.client_tag, "app");
+    assert_eq!(reverted.internal_level, 7);
+}
+
+#[test]
+fn test_one_way_conversion() {
+    // payload类型没有实现Default, 只生成from方向时不需要Default约束
+    struct NoDefault(u8);
+    let converted: OneWayDto<NoDefault> = OneWay {
+        item_name: "item".to_string(),
+        payload: NoDefault(3),
+    }
+    .into();
+    assert_eq!(converted.itemName, "item");
+    assert_eq!(converted.payload.0, 3);
+
+    let form = OneWayForm {
+        itemName: "form".to_string(),
+        payload: 9,
+    };
+    assert_eq!(form.payload, 9);
+    let reverted: OneWay<u32> = form.into();
+    assert_eq!(reverted.item_name, "form");
+    // skip_into字段转换回原始结构体时使用默认值
+    assert_eq!(reverted.payload, 0);
+}
+
+#[test]
+fn test_visibility_configuration() {
+    let dto: visibility::AccountDto = visibility::account_dto();
+    assert_eq!(dto.accountId, 7);
+    assert_eq!(dto.displayName, "private");
+    // add_field默认使用生成结构体的可见性
+    assert_eq!(dto.version, 0);
+    assert_eq!(dto.etag, "");
+}
+
+#[test]
+fn test_attribute_forwarding() {
+    let original = AttrForward {
+        record_id: 1,
+        note: "n".to_string(),
+    };
+    let forwarded: ForwardedDto = AttrForward {
+        record_id: 1,
+        note: "n".to_string(),
+    }
+    .into();
+    assert_eq!(serde_json::to_string(&forwarded).unwrap(), r#"{"ID":1}"#);
+
+    // forward_attrs = false时只保留attr配置的属性
+    let plain: PlainDto = original.into();
+    assert_eq!(
+        serde_json::to_string(&plain.clone()).unwrap(),
+        r#"{"uid":1,"note":"n"}"#
+    );
+
+    // 只透传doc属性, serde属性不会被透传
+    let docs: DocsDto = serde_json::from_str(r#"{"recordId":2,"note":"d","extra":true}"#).unwrap();
+    assert_eq!(docs.recordId, 2);
+    assert_eq!(docs.note, "d");
+}
+
+#[test]
+fn test_doc_comments() {
+    let dto: ProfileDto = Profile {
+        user_name: "name".to_string(),
+        nick_name: "nick".to_string(),
+    }
+    .into();
+    assert_eq!(dto.userName, "name");
+    assert_eq!(dto.nickName, "nick");
+    assert_eq!(dto.avatarUrl, "");
+}
+
+#[test]
+fn test_patch_struct() {
+    let mut member = Member {
+        member_id: 1,
+        display_name: "old".to_string(),
+        tags: vec!["a".to_string()],
+        home_address: Address {
+            street_name: "Main".to_string(),
+            zip_code: 1000,
+        },
+    };
+    let patch: MemberPatch =
+        serde_json::from_str(r#"{"displayName":"new","homeAddress":{"zipCode":2000}}"#).unwrap();
+    member.apply_patch(patch);
+
+    assert_eq!(member.member_id, 1);
+    assert_eq!(member.display_name, "new");
+    assert_eq!(member.tags, vec!["a".to_string()]);
+    // 嵌套的patch只修改Some字段
+    assert_eq!(member.home_address.street_name, "Main");
+    assert_eq!(member.home_address.zip_code, 2000);
+
+    member.apply_patch(MemberPatch {
+        tags: Some(vec![]),
+        ..Default::default()
+    });
+    assert!(member.tags.is_empty());
+    assert_eq!(member.display_name, "new");
+}
+
+#[test]
+fn test_patch_diff() {
+    let before = Member {
+        member_id: 1,
+        display_name: "old".to_string(),
+        tags: vec!["a".to_string()],
+        home_address: Address {
+            street_name: "Main".to_string(),
+            zip_code: 1000,
+        },
+    };
+    let mut after = before.clone();
+    after.member_id = 2;
+    after.display_name = "new".to_string();
+    after.home_address.zip_code = 2000;
+
+    let diff = before.diff(&after);
+    assert_eq!(diff.displayName, Some("new".to_string()));
+    assert_eq!(diff.tags, None);
+    let address_diff = diff.homeAddress.as_ref().unwrap();
+    assert_eq!(address_diff.streetName, None);
+    assert_eq!(address_diff.zipCode, Some(2000));
+    assert!(!diff.is_empty());
+    assert!(before.diff(&before).is_empty());
+    assert!(before.diff(&before).homeAddress.is_none());
+
+    // 应用diff后除field_skip字段外与after一致
+    let mut patched = before.clone();
+    patched.apply_patch(diff);
+    assert_eq!(patched.member_id, 1);
+    patched.member_id = after.member_id;
+    assert_eq!(patched, after);
+}
+
+#[test]
+fn test_builder() {
+    let vo = ShipmentVo::<Vec<u8>>::builder()
+        .shipmentId(7u64)
+        .carrierName("ups")
+        .payload(vec![1u8])
+        .build()
+        .unwrap();
+    assert_eq!(vo.shipmentId, 7);
+    assert_eq!(vo.carrierName, "ups");
+    assert_eq!(vo.payload, vec![1u8]);
+    // add_field和skip_from字段使用默认值
+    assert_eq!(vo.trackingUrl, "");
+    assert_eq!(vo.retryCount, 3);
+
+    let vo = ShipmentVo::<u8>::builder()
+        .retryCount(1u8)
+        .trackingUrl("https://example.com")
+        .carrierName("dhl")
+        .payload(0u8)
+        .shipmentId(1u64)
+        .build()
+        .unwrap();
+    assert_eq!(vo.retryCount, 1);
+    assert_eq!(vo.trackingUrl, "https://example.com");
+    let original: Shipment<u8> = vo.into();
+    assert_eq!(original.retry_count, 1);
+
+    let err = ShipmentVo::<u8>::builder()
+        .carrierName("dhl")
+        .build()
+        .unwrap_err();
+    assert_eq!(err, "missing required fields: shipmentId, payload");
+}
+
+#[test]
+fn test_field_name_metadata() {
+    assert_eq!(
+        ShipmentVo::<u8>::FIELD_NAMES,
+        &[
+            "shipmentId",
+            "carrierName",
+            "retryCount",
+            "payload",
+            "trackingUrl"
+        ]
+    );
+    assert_eq!(
+        ShipmentVo::<u8>::snake_to_camel_field("carrier_name"),
+        Some("carrierName")
+    );
+    assert_eq!(
+        ShipmentVo::<u8>::camel_to_snake_field("carrierName"),
+        Some("carrier_name")
+    );
+    // 新增字段没有对应的原始字段
+    assert_eq!(ShipmentVo::<u8>::camel_to_snake_field("trackingUrl"), None);
+    assert_eq!(ShipmentVo::<u8>::snake_to_camel_field("unknown"), None);
+
+    // 跳过的字段不在字段名列表中
+    assert_eq!(FieldConfigDto::FIELD_NAMES, &["specialName", "itemCount"]);
+    assert_eq!(FieldConfigDto::snake_to_camel_field("internal_id"), None);
+    assert_eq!(
+        MemberPatch::FIELD_NAMES,
+        &["displayName", "tags", "homeAddress"]
+    );
+}
+
+#[test]
+fn test_naming_matches_runtime() {
+    // 派生宏生成的字段名与运行时命名转换及共享用例表一致
+    assert_eq!(NamingVo::FIELD_NAMES.len(), 10);
+    for &(input, camel, _, _) in fixtures::CASES {
+        if let Some(field) = NamingVo::snake_to_camel_field(input) {
+            assert_eq!(field, camel);
+            assert_eq!(field, snake_to_camel_runtime::to_camel(input));
+        }
+    }
+}
+
+#[test]
+fn test_acronyms() {
+    let options = snake_to_camel_runtime::CaseOptions::new().acronyms(fixtures::ACRONYMS);
+    assert_eq!(AcronymVo::FIELD_NAMES.len(), 6);
+    for &(input, camel, _, _) in fixtures::ACRONYM_CASES {
+        if let Some(field) = AcronymVo::snake_to_camel_field(input) {
+            assert_eq!(field, camel);
+            assert_eq!(
+                field,
+                snake_to_camel_runtime::to_camel_with(input, &options)
+            );
+        }
+    }
+    let vo: AcronymVo = Acronym {
+        user_id: 1,
+        http_url: 2,
+        url: 3,
+        user_id_url: 4,
+        http_server_id: 5,
+        identity: 6,
+    }
+    .into();
+    assert_eq!(vo.userID + vo.httpURL + vo.userIDURL + vo.httpServerID, 12);
+    assert_eq!(vo.url + vo.identity, 9);
+}
+
+#[test]
+fn test_digits() {
+    let credential = Credential {
+        sha256hex: "abc".to_string(),
+        oauth2token: Oauth2token {
+            v2token: "token".to_string(),
+            address_line_1: 1,
+        },
+    };
+    let vo: CredentialVo = credential.into();
+    assert_eq!(vo.sha256Hex, "abc");
+    let token: &Oauth2TokenVo = &vo.oauth2Token;
+    assert_eq!(token.v2Token, "token");
+    assert_eq!(token.addressLine1, 1);
+    assert_eq!(
+        CredentialVo::FIELD_NAMES,
+        &["sha256Hex", "oauth2Token"]
+    );
+
+    let vo: PreservedVo = Preserved {
+        address_line_1: 1,
+        v2_token: 2,
+    }
+    .into();
+    assert_eq!(vo.addressLine_1 + vo.v2_token, 3);
+
+    // 与共享用例表一致
+    for &(input, _, new_word, preserve) in fixtures::DIGIT_CASES {
+        if let Some(field) = Oauth2TokenVo::snake_to_camel_field(input) {
+            assert_eq!(field, new_word);
+        }
+        if let Some(field) = PreservedVo::snake_to_camel_field(input) {
+            assert_eq!(field, preserve);
+        }
+    }
+}
+
+#[test]
+fn test_dictionary() {
+    let line = OrderLine {
+        order_qty: 2,
+        ship_addr_line_1: "Main St".to_string(),
+        home_tel: "123".to_string(),
+    };
+    let vo: OrderLineVo = line.into();
+    assert_eq!(vo.orderQuantity, 2);
+    assert_eq!(vo.shipAddressLine1, "Main St");
+    assert_eq!(vo.homePhoneNumber, "123");
+    // 用例表中的替换表与dictionary文件一致
+    let names: toml::Table = include_str!("names.toml").parse().unwrap();
+    assert_eq!(names.len(), fixtures::DICTIONARY.len());
+    for &(word, replacement) in fixtures::DICTIONARY {
+        assert_eq!(names[word].as_str(), Some(replacement));
+    }
+    for &(input, camel, _) in fixtures::DICTIONARY_CASES {
+        if let Some(field) = OrderLineVo::snake_to_camel_field(input) {
+            assert_eq!(field, camel);
+        }
+    }
+    assert_eq!(OrderLine::from(vo).order_qty, 2);
+}
+
+#[test]
+fn test_keep_types() {
+    let invoice = Invoice {
+        total_amount: Money(300),
+        line_amounts: vec![Money(100), Money(200)],
+        due_date: chrono::NaiveDate::from_ymd_opt(2024, 1, 31),
+    };
+    let vo: InvoiceVo = invoice.into();
+    assert_eq!(vo.totalAmount, Money(300));
+    assert_eq!(vo.lineAmounts, vec![Money(100), Money(200)]);
+    assert_eq!(vo.dueDate, chrono::NaiveDate::from_ymd_opt(2024, 1, 31));
+    assert_eq!(Invoice::from(vo).total_amount, Money(300));
+}
+
+#[test]
+fn test_type_name_resolution() {
+    let cart = Cart {
+        cart_items: vec![CartItem { item_id: 1 }],
+        coupons: Vec::new(),
+        gift_coupon: Coupon {
+            coupon_code: "GIFT".to_string(),
+        },
+    };
+    let dto = CartDto::from(cart.clone());
+    let _: &Vec<CartItemDto> = &dto.cartItems;
+    let _: &Vec<CouponDto> = &dto.coupons;
+    assert_eq!(dto.giftCoupon.couponCode, "GIFT");
+    let view = CartView::from(cart.clone());
+    let _: &Vec<ItemView> = &view.cartItems;
+    let _: &Vec<CouponView> = &view.coupons;
+    let _: &CouponDto = &view.giftCoupon;
+    assert_eq!(view.cartItems[0].itemId, 1);
+    assert_eq!(Cart::from(view), cart);
+}
+
+#[test]
+fn test_module_paths() {
+    let address = models::Address {
+        street_name: "Main St".to_string(),
+    };
+    let customer = Customer {
+        home_address: address.clone(),
+        address_key: 42,
+    };
+    let vo = CustomerVo::from(customer.clone());
+    let _: &models::AddressVo = &vo.homeAddress;
+    let _: &u64 = &vo.addressKey;
+    assert_eq!(Customer::from(vo), customer);
+
+    let supplier = Supplier {
+        office_addresses: vec![address],
+    };
+    let vo = SupplierVo::from(supplier.clone());
+    let _: &Vec<dto::AddressVo> = &vo.officeAddresses;
+    assert_eq!(vo.officeAddresses[0].street, "Main St");
+    assert_eq!(Supplier::from(vo), supplier);
+}
+
+#[test]
+fn test_module_path_generics() {
+    let page = models::Page {
+        page_items: vec![models::Address {
+            street_name: "Main St".to_string(),
+        }],
+    };
+    let branch = Branch {
+        address_page: page.clone(),
+    };
+    let vo = BranchVo::from(branch.clone());
+    let _: &models::PageVo<models::AddressVo> = &vo.addressPage;
+    assert_eq!(vo.addressPage.pageItems[0].streetName, "Main St");
+    assert_eq!(Branch::from(vo), branch);
+
+    let warehouse = Warehouse { address_page: page };
+    let vo = WarehouseVo::from(warehouse.clone());
+    let _: &dto::PageVo<dto::AddressVo> = &vo.addressPage;
+    assert_eq!(vo.addressPage.items[0].street, "Main St");
+    assert_eq!(Warehouse::from(vo), warehouse);
+}
+
+#[test]
+fn test_module() {
+    let product = catalog::Product {
+        categories: vec![catalog::Category {
+            category_name: "Books".to_string(),
+        }],
+        unit_price: Money(15),
+    };
+    let vo = catalog::dto::ProductVo::from(product.clone());
+    let _: &Vec<catalog::dto::CategoryVo> = &vo.categories;
+    assert_eq!(vo.categories[0].categoryName, "Books");
+    assert_eq!(vo.unitPrice, Money(15));
+    assert_eq!(catalog::Product::from(vo), product);
+    // 未配置module的id生成在原模块中
+    let row = catalog::CategoryRow::from(product.categories[0].clone());
+    assert_eq!(row.categoryName, "Books");
+    assert_eq!(shipping::parcel_id(9), 18);
+}
+
+#[test]
+fn test_camel_struct() {
+    let subscriber = Subscriber {
+        userID: 7,
+        displayName: "Ann".to_string(),
+    };
+    assert_eq!(Subscriber::FIELD_NAMES, &["userID", "displayName"]);
+    assert_eq!(Subscriber::snake_to_camel_field("user_id"), Some("userID"));
+    assert_eq!(Subscriber::camel_to_snake_field("displayName"), Some("display_name"));
+    let built = Subscriber::builder()
+        .userID(7u64)
+        .displayName("Ann")
+        .build()
+        .unwrap();
+    assert_eq!(built, subscriber);
+    assert_eq!(
+        Subscriber::builder().userID(7u64).build().unwrap_err(),
+        "missing required fields: displayName"
+    );
+}
+
+#[test]
+fn test_camel_mirror() {
+    let vo = RangeVo::from(1..5);
+    assert_eq!(vo, RangeVo { start: 1, end: 5 });
+    assert_eq!(std::ops::Range::from(vo), 1..5);
+
+    let config = vendor::ServerConfig {
+        listen_port: 8080,
+        max_connections: 64,
+        debug_mode: false,
+    };
+    let vo = ApiServerConfig::from(config);
+    assert_eq!(vo.listenPort, 8080);
+    assert_eq!(vo.maxConnections, 64);
+    let config = vendor::ServerConfig::from(vo);
+    assert!(config.debug_mode);
+}
+
+#[test]
+fn test_camel_mirror_patch() {
+    // camel_mirror不支持patch, 只检查宏自身的错误信息, 不依赖编译器版本
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/camel_mirror_patch.rs");
+}
+
+#[test]
+fn test_dictionary_invalid() {
+    // dictionary中的替换值不能组成标识符时编译报错
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/dictionary_invalid.rs");
+}
+
+#[test]
+fn test_builder_docs() {
+    // 生成的builder在deny(missing_docs)的crate中可以编译
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/builder_docs.rs");
+}
+
+#[test]
+fn test_patch_custom_type() {
+    let before = Ticket {
+        title: "bug".to_string(),
+        status: Status { code: 1 },
+    };
+    let after = Ticket {
+        title: "bug".to_string(),
+        status: Status { code: 2 },
+    };
+    let diff = before.diff(&after);
+    assert_eq!(diff.title, None);
+    assert_eq!(diff.status.as_ref().map(|status| status.code), Some(2));
+
+    let mut patched = before.clone();
+    patched.apply_patch(diff);
+    assert_eq!(patched, after);
+}
+
+#[test]
+fn test_patch_container_nested_type() {
+    let address = |zip_code| Address {
+        street_name: "Main St".to_string(),
+        zip_code,
+    };
+    let before = Household {
+        primary_address: None,
+        addresses: vec![address(1)],
+    };
+    let after = Household {
+        primary_address: Some(address(2)),
+        addresses: vec![address(1), address(3)],
+    };
+    let diff = before.diff(&after);
+    let _: &Option<Option<Address>> = &diff.primaryAddress;
+    assert_eq!(diff.primaryAddress, Some(Some(address(2))));
+    assert_eq!(diff.addresses.as_ref().map(Vec::len), Some(2));
+
+    let mut patched = before.clone();
+    patched.apply_patch(diff);
+    assert_eq!(patched, after);
+    patched.apply_patch(HouseholdPatch {
+        primaryAddress: Some(None),
+        ..Default::default()
+    });
+    assert_eq!(patched.primary_address, None);
+    assert_eq!(patched.addresses, after.addresses);
+}
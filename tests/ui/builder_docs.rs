@@ -0,0 +1,34 @@
+//! 生成的builder带有文档, 可以在`#![deny(missing_docs)]`的crate中使用
+#![deny(missing_docs)]
+
+use snake_to_camel::{GenCamelCase, camel_struct};
+
+/// 订单
+#[derive(GenCamelCase)]
+#[gen_camel(name = "OrderDto", builder)]
+pub struct Order {
+    /// 订单号
+    pub order_id: u64,
+    /// 数量
+    pub order_qty: u32,
+}
+
+/// 客户
+#[camel_struct(builder)]
+pub struct Customer {
+    /// 客户名
+    pub customer_name: String,
+}
+
+fn main() {
+    let order = OrderDto::builder()
+        .orderId(1u64)
+        .orderQty(2u32)
+        .build()
+        .unwrap();
+    let customer = Customer::builder()
+        .customerName("name".to_string())
+        .build()
+        .unwrap();
+    let _ = (Order::from(order), customer);
+}
@@ -0,0 +1,17 @@
+mod vendor {
+    pub struct Endpoint {
+        pub host_name: String,
+        pub port_number: u16,
+    }
+}
+
+// apply_patch是原始结构体上的方法, 不能为外部结构体生成
+snake_to_camel::camel_mirror!(
+    #[gen_camel(patch)]
+    vendor::Endpoint {
+        pub host_name: String,
+        pub port_number: u16,
+    } => EndpointVo
+);
+
+fn main() {}
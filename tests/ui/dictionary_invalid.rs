@@ -0,0 +1,10 @@
+use snake_to_camel::GenCamelCase;
+
+// dictionary中的替换值不能组成标识符, 路径相对于trybuild在target/tests/trybuild下生成的项目目录
+#[derive(GenCamelCase)]
+#[gen_camel(dictionary = "../../../../tests/ui/invalid_names.toml")]
+struct Order {
+    order_qty: u32,
+}
+
+fn main() {}